@@ -1,10 +1,14 @@
 mod parser;
 
-pub(crate) use parser::parse_tailwind;
+use crate::TailwindVersion;
+
+pub use parser::parse_tailwind;
 pub(crate) use parser::take_until_unbalanced;
 
+/// A parsed Tailwind class (e.g. `hover:-mt-[2px]`).
 #[derive(Clone, Debug, PartialEq, Default)]
-pub(crate) struct AstStyle<'a> {
+pub struct AstStyle<'a> {
+    /// The class as written
     pub source: &'a str,
     /// Is a `!important` style
     pub important: bool,
@@ -16,6 +20,28 @@ pub(crate) struct AstStyle<'a> {
     pub elements: Vec<&'a str>,
     /// Is a arbitrary value
     pub arbitrary: Option<&'a str>,
+    /// Modifier after `/` (e.g. `50` in `bg-red-500/50`, `[17px]` in `text-sm/[17px]`)
+    pub postfix: Option<&'a str>,
+}
+
+impl<'a> AstStyle<'a> {
+    /// Elements with the postfix joined back onto the last element.
+    ///
+    /// Needed when the `/` is part of the value, such as fractions (e.g. `aspect-16/9`)
+    pub fn elements_with_postfix(&self) -> Option<Vec<&'a str>> {
+        let postfix = self.postfix?;
+        let last = self.elements.last()?;
+        if self.arbitrary.is_some() {
+            return None;
+        }
+        let offset = |s: &str| s.as_ptr() as usize - self.source.as_ptr() as usize;
+        let joined = &self.source[offset(last)..offset(postfix) + postfix.len()];
+
+        let mut elements = self.elements.clone();
+        elements.pop();
+        elements.push(joined);
+        Some(elements)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Default)]
@@ -36,13 +62,15 @@ enum ASTVariant<'a> {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) struct AstParseOptions<'a> {
+pub struct AstParseOptions<'a> {
     /// Custom prefix for modifiers in Tailwind classes
     /// <https://tailwindcss.com/docs/configuration#prefix>
     pub prefix: &'a str,
     /// Custom separator for modifiers in Tailwind classes
     /// <https://tailwindcss.com/docs/configuration#separator>
     pub separator: &'a str,
+    /// Tailwind version syntax to parse
+    pub version: TailwindVersion,
 }
 
 impl Default for AstParseOptions<'static> {
@@ -50,6 +78,7 @@ impl Default for AstParseOptions<'static> {
         Self {
             prefix: "",
             separator: ":",
+            version: TailwindVersion::V3,
         }
     }
 }
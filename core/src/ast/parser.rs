@@ -2,13 +2,14 @@ use nom::{
     branch::alt,
     bytes::complete::{tag, take_till1, take_while1},
     character::complete::char,
-    combinator::opt,
+    combinator::{opt, recognize},
     multi::many0,
     sequence::{delimited, tuple},
     IResult,
 };
 
 use super::{ASTVariant, AstElements, AstParseOptions, AstStyle};
+use crate::TailwindVersion;
 
 pub fn parse_tailwind<'a>(
     class: &[&'a str],
@@ -29,15 +30,36 @@ fn parse_style<'a>(
     input: &'a str,
     options: &AstParseOptions<'a>,
 ) -> IResult<&'a str, AstStyle<'a>> {
-    let (rest, (variants, important, negative, elements, arbitrary)) = tuple((
+    let is_v4 = options.version == TailwindVersion::V4;
+
+    // v4 prefix looks like a variant: `tw:hover:flex`
+    let (after_prefix, element_prefix) = if is_v4 {
+        let (rest, _) = parse_v4_prefix(options.prefix, options.separator, input)?;
+        (rest, "")
+    } else {
+        (input, options.prefix)
+    };
+
+    // https://tailwindcss.com/docs/upgrade-guide#important-modifier
+    let (body, important_suffix) = match after_prefix.strip_suffix('!') {
+        Some(body) if is_v4 => (body, true),
+        _ => (after_prefix, false),
+    };
+
+    let (rest, (variants, important, negative, elements, arbitrary, postfix)) = tuple((
         many0(|s| parse_variant(options.separator, s)),
         opt(char('!')),
         opt(char('-')),
-        opt(|s| parse_elements(options.prefix, s)),
-        opt(parse_arbitrary),
-    ))(input)?;
+        opt(|s| parse_elements(element_prefix, s)),
+        opt(|s| parse_value(is_v4, s)),
+        opt(|s| parse_postfix(is_v4, s)),
+    ))(body)?;
 
-    let source = &input[..input.len() - rest.len()];
+    let source = if rest.is_empty() {
+        input
+    } else {
+        &input[..input.len() - rest.len() - usize::from(important_suffix)]
+    };
 
     let variants = variants
         .into_iter()
@@ -52,22 +74,46 @@ fn parse_style<'a>(
         rest,
         AstStyle {
             source,
-            important: important.is_some(),
+            important: important.is_some() || important_suffix,
             negative: negative.is_some(),
             variants,
             elements: elements.unwrap_or_default().elements,
             arbitrary,
+            postfix,
         },
     ))
 }
 
+// https://tailwindcss.com/docs/styling-with-utility-classes#using-the-prefix-option
+#[inline]
+fn parse_v4_prefix<'a>(
+    prefix: &'a str,
+    separator: &'a str,
+    input: &'a str,
+) -> IResult<&'a str, ()> {
+    if prefix.is_empty() {
+        return Ok((input, ()));
+    }
+    let (rest, _) = tuple((tag(prefix), tag(separator)))(input)?;
+    Ok((rest, ()))
+}
+
+#[inline]
+fn parse_value(is_v4: bool, input: &str) -> IResult<&str, &str> {
+    if is_v4 {
+        alt((parse_arbitrary, parse_css_variable))(input)
+    } else {
+        parse_arbitrary(input)
+    }
+}
+
 #[inline]
 fn parse_elements<'a>(prefix: &'a str, input: &'a str) -> IResult<&'a str, AstElements<'a>> {
     #[inline]
     fn parse_head(input: &str) -> IResult<&str, &str> {
         let stop = |c: char| -> bool {
             // space
-            matches!(c, ' ' | '\n' | '\r' | '-' | '[' | ']' | '(' | ')')
+            matches!(c, ' ' | '\n' | '\r' | '-' | '[' | ']' | '(' | ')' | '/')
         };
         take_till1(stop)(input)
     }
@@ -96,18 +142,23 @@ fn parse_variant<'a>(separator: &'a str, input: &'a str) -> IResult<&'a str, AST
 }
 
 // https://tailwindcss.com/docs/hover-focus-and-other-states#using-arbitrary-variants
+// `@md`, `*` and `group-hover/item` are also valid variants
 #[inline]
-fn parse_normal_variant(input: &str) -> IResult<&str, ASTVariant> {
-    let parser = take_while1(|c: char| c.is_alphanumeric() || c == '-');
+fn parse_normal_variant(input: &str) -> IResult<&str, ASTVariant<'_>> {
+    let parser = take_while1(|c: char| c.is_alphanumeric() || matches!(c, '-' | '@' | '*' | '/'));
     let (rest, result) = parser(input)?;
     Ok((rest, ASTVariant::Normal(result)))
 }
 
 // https://tailwindcss.com/docs/hover-focus-and-other-states#data-attributes
 // https://tailwindcss.com/docs/hover-focus-and-other-states#supports-rules
+// Also `aria-[..]`, `has-[..]`, `nth-[..]`, `not-[..]`, `@[..]`, etc.
 #[inline]
-fn parse_data_attribute_variant(input: &str) -> IResult<&str, ASTVariant> {
-    let tag_prefix = alt((tag("data-"), tag("supports-")));
+fn parse_data_attribute_variant(input: &str) -> IResult<&str, ASTVariant<'_>> {
+    let tag_prefix = tuple((
+        take_while1(|c: char| c.is_alphanumeric() || matches!(c, '-' | '@')),
+        tag("["),
+    ));
     let mut parser = delimited(tag_prefix, take_till1(|c| c == ']'), tag("]"));
     let (rest, _) = parser(input)?;
     let entire_variant = &input[..input.len() - rest.len()];
@@ -116,7 +167,7 @@ fn parse_data_attribute_variant(input: &str) -> IResult<&str, ASTVariant> {
 
 // https://tailwindcss.com/docs/hover-focus-and-other-states#using-arbitrary-variants
 #[inline]
-fn parse_arbitrary_attribute_variant(input: &str) -> IResult<&str, ASTVariant> {
+fn parse_arbitrary_attribute_variant(input: &str) -> IResult<&str, ASTVariant<'_>> {
     let mut parser = delimited(tag("["), take_until_unbalanced('[', ']'), tag("]"));
     let (rest, _) = parser(input)?;
     let entire_variant = &input[..input.len() - rest.len()];
@@ -130,6 +181,38 @@ fn parse_arbitrary(input: &str) -> IResult<&str, &str> {
     Ok((rest, arbitrary))
 }
 
+// https://tailwindcss.com/docs/background-color#changing-the-opacity
+// https://tailwindcss.com/docs/font-size#setting-the-line-height
+#[inline]
+fn parse_postfix(is_v4: bool, input: &str) -> IResult<&str, &str> {
+    let (input, _) = char('/')(input)?;
+    let arbitrary = recognize(delimited(
+        tag("["),
+        take_until_unbalanced('[', ']'),
+        tag("]"),
+    ));
+    let value = take_while1(|c: char| c.is_alphanumeric() || matches!(c, '.' | '%'));
+    if is_v4 {
+        let css_variable = recognize(delimited(
+            tag("("),
+            take_until_unbalanced('(', ')'),
+            tag(")"),
+        ));
+        alt((arbitrary, css_variable, value))(input)
+    } else {
+        alt((arbitrary, value))(input)
+    }
+}
+
+// `bg-(--brand)` is shorthand for `bg-[var(--brand)]`, the variable is kept as the arbitrary value.
+// https://tailwindcss.com/docs/adding-custom-styles#using-arbitrary-values
+#[inline]
+fn parse_css_variable(input: &str) -> IResult<&str, &str> {
+    let parser = delimited(tag("("), take_until_unbalanced('(', ')'), tag(")"));
+    let (rest, (_, variable)) = tuple((opt(char('-')), parser))(input)?;
+    Ok((rest, variable))
+}
+
 // https://stackoverflow.com/questions/70630556/parse-allowing-nested-parentheses-in-nom
 pub fn take_until_unbalanced(
     opening_bracket: char,
@@ -183,7 +266,7 @@ pub fn take_until_unbalanced(
 mod test {
     use super::*;
 
-    fn parse_tailwind(class: &str) -> Vec<Result<AstStyle, &str>> {
+    fn parse_tailwind(class: &str) -> Vec<Result<AstStyle<'_>, &str>> {
         let options = AstParseOptions::default();
         let split = class.split_whitespace().collect::<Vec<_>>();
         super::parse_tailwind(split.as_slice(), options)
@@ -201,6 +284,7 @@ mod test {
                 variants: vec![],
                 elements: vec!["flex"],
                 arbitrary: None,
+                postfix: None,
             }),
             Ok(AstStyle {
                 source: "items-center",
@@ -209,6 +293,7 @@ mod test {
                 variants: vec![],
                 elements: vec!["items", "center"],
                 arbitrary: None,
+                postfix: None,
             }),
             Ok(AstStyle {
                 source: "justify-between",
@@ -217,6 +302,7 @@ mod test {
                 variants: vec![],
                 elements: vec!["justify", "between"],
                 arbitrary: None,
+                postfix: None,
             }),
         ];
 
@@ -230,6 +316,7 @@ mod test {
         let options = AstParseOptions {
             prefix: "tw-",
             separator: "|",
+            version: TailwindVersion::V3,
         };
         let result = super::parse_tailwind(&class, options);
         let expected = vec![Ok(AstStyle {
@@ -239,6 +326,7 @@ mod test {
             variants: vec!["dark", "hover"],
             elements: vec!["flex"],
             arbitrary: None,
+            postfix: None,
         })];
 
         assert_eq!(result, expected)
@@ -255,6 +343,7 @@ mod test {
             variants: vec![],
             elements: vec!["my", "2"],
             arbitrary: None,
+            postfix: None,
         })];
         assert_eq!(result, expected)
     }
@@ -270,6 +359,7 @@ mod test {
             variants: vec![],
             elements: vec!["bg", "blue", "500"],
             arbitrary: None,
+            postfix: None,
         })];
         assert_eq!(result, expected)
     }
@@ -285,6 +375,7 @@ mod test {
             variants: vec!["hover", "md"],
             elements: vec!["flex"],
             arbitrary: None,
+            postfix: None,
         })];
         assert_eq!(result, expected)
     }
@@ -300,6 +391,7 @@ mod test {
             variants: vec!["aria-checked"],
             elements: vec!["true"],
             arbitrary: None,
+            postfix: None,
         })];
         assert_eq!(result, expected)
     }
@@ -315,6 +407,7 @@ mod test {
             variants: vec!["[&:nth-child(3)]"],
             elements: vec!["underline"],
             arbitrary: None,
+            postfix: None,
         })];
         assert_eq!(result, expected)
     }
@@ -336,6 +429,7 @@ mod test {
                 variants: vec!["data-[open]"],
                 elements: vec!["flex", "col"],
                 arbitrary: None,
+                postfix: None,
             }),
             Ok(AstStyle {
                 source: "data-[close]:flex-row",
@@ -344,6 +438,7 @@ mod test {
                 variants: vec!["data-[close]"],
                 elements: vec!["flex", "row"],
                 arbitrary: None,
+                postfix: None,
             }),
         ];
 
@@ -361,6 +456,7 @@ mod test {
             variants: vec!["dark", "lg", "hover", "[&>*]"],
             elements: vec!["line", "through"],
             arbitrary: None,
+            postfix: None,
         })];
         assert_eq!(result, expected);
     }
@@ -400,6 +496,7 @@ mod test {
             variants: vec!["[&[data-open]]"],
             elements: vec!["line", "through"],
             arbitrary: None,
+            postfix: None,
         })];
 
         assert_eq!(result, expected);
@@ -418,6 +515,7 @@ mod test {
                 variants: vec![],
                 elements: vec!["flex"],
                 arbitrary: None,
+                postfix: None,
             }),
         ];
         assert_eq!(result, expected)
@@ -434,7 +532,146 @@ mod test {
             variants: vec!["[&>*]"],
             elements: vec![],
             arbitrary: Some("color:blue"),
+            postfix: None,
         })];
         assert_eq!(result, expected)
     }
+
+    fn parse_tailwind_v4(class: &str) -> Vec<Result<AstStyle<'_>, &str>> {
+        let options = AstParseOptions {
+            version: TailwindVersion::V4,
+            ..Default::default()
+        };
+        let split = class.split_whitespace().collect::<Vec<_>>();
+        super::parse_tailwind(split.as_slice(), options)
+    }
+
+    #[test]
+    fn v4_css_variable() {
+        let result = parse_tailwind_v4("bg-(--brand) text-(length:--size)");
+        let expected = vec![
+            Ok(AstStyle {
+                source: "bg-(--brand)",
+                important: false,
+                negative: false,
+                variants: vec![],
+                elements: vec!["bg"],
+                arbitrary: Some("--brand"),
+                postfix: None,
+            }),
+            Ok(AstStyle {
+                source: "text-(length:--size)",
+                important: false,
+                negative: false,
+                variants: vec![],
+                elements: vec!["text"],
+                arbitrary: Some("length:--size"),
+                postfix: None,
+            }),
+        ];
+        assert_eq!(result, expected);
+
+        let result = parse_tailwind("bg-(--brand)");
+        assert_eq!(
+            result,
+            vec![Err("bg-(--brand)")],
+            "v3 has no css variable shorthand"
+        );
+    }
+
+    #[test]
+    fn v4_important_suffix() {
+        let result = parse_tailwind_v4("hover:bg-red-500!");
+        let expected = vec![Ok(AstStyle {
+            source: "hover:bg-red-500!",
+            important: true,
+            negative: false,
+            variants: vec!["hover"],
+            elements: vec!["bg", "red", "500"],
+            arbitrary: None,
+            postfix: None,
+        })];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn v4_prefix() {
+        let options = AstParseOptions {
+            prefix: "tw",
+            separator: ":",
+            version: TailwindVersion::V4,
+        };
+        let result = super::parse_tailwind(&["tw:hover:flex flex"], options);
+        let expected = vec![
+            Ok(AstStyle {
+                source: "tw:hover:flex",
+                important: false,
+                negative: false,
+                variants: vec!["hover"],
+                elements: vec!["flex"],
+                arbitrary: None,
+                postfix: None,
+            }),
+            Err("flex"),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn v4_variants() {
+        let result = parse_tailwind_v4("@md:*:not-[.open]:flex");
+        let expected = vec![Ok(AstStyle {
+            source: "@md:*:not-[.open]:flex",
+            important: false,
+            negative: false,
+            variants: vec!["@md", "*", "not-[.open]"],
+            elements: vec!["flex"],
+            arbitrary: None,
+            postfix: None,
+        })];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn postfix_modifier() {
+        let result = parse_tailwind("bg-red-500/50 text-sm/[17px] bg-[#fff]/[.3]");
+        let expected = vec![
+            Ok(AstStyle {
+                source: "bg-red-500/50",
+                important: false,
+                negative: false,
+                variants: vec![],
+                elements: vec!["bg", "red", "500"],
+                arbitrary: None,
+                postfix: Some("50"),
+            }),
+            Ok(AstStyle {
+                source: "text-sm/[17px]",
+                important: false,
+                negative: false,
+                variants: vec![],
+                elements: vec!["text", "sm"],
+                arbitrary: None,
+                postfix: Some("[17px]"),
+            }),
+            Ok(AstStyle {
+                source: "bg-[#fff]/[.3]",
+                important: false,
+                negative: false,
+                variants: vec![],
+                elements: vec!["bg"],
+                arbitrary: Some("#fff"),
+                postfix: Some("[.3]"),
+            }),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn postfix_fraction() {
+        let style = parse_tailwind("aspect-16/9").remove(0).unwrap();
+        assert_eq!(style.elements, vec!["aspect", "16"]);
+        assert_eq!(style.postfix, Some("9"));
+        assert_eq!(style.elements_with_postfix(), Some(vec!["aspect", "16/9"]));
+    }
 }
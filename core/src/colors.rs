@@ -0,0 +1,47 @@
+/// <https://tailwindcss.com/docs/customizing-colors#default-color-palette>
+const PALETTE: &[&str] = &[
+    "slate", "gray", "zinc", "neutral", "stone", "red", "orange", "amber", "yellow", "lime",
+    "green", "emerald", "teal", "cyan", "sky", "blue", "indigo", "violet", "purple", "fuchsia",
+    "pink", "rose",
+];
+
+const SHADES: &[&str] = &[
+    "50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950",
+];
+
+const KEYWORDS: &[&str] = &["inherit", "current", "transparent", "black", "white"];
+
+/// Returns true if the CollisionId only takes a color (e.g. `background-color`, `border-color-x`).
+pub fn is_color_collision_id(collision_id: &str) -> bool {
+    matches!(
+        collision_id,
+        "background-color"
+            | "text-color"
+            | "text-decoration-color"
+            | "divide-color"
+            | "outline-color"
+            | "ring-color"
+            | "ring-offset-color"
+            | "box-shadow-color"
+            | "accent-color"
+            | "caret-color"
+    ) || collision_id.starts_with("border-color")
+}
+
+/// Returns true if the elements end with a color from the default palette (e.g. `["bg", "red", "500"]`).
+pub fn is_default_color(elements: &[&str]) -> bool {
+    match elements {
+        [.., color] if KEYWORDS.contains(color) => true,
+        [.., color, shade] => PALETTE.contains(color) && SHADES.contains(shade),
+        _ => false,
+    }
+}
+
+#[test]
+fn test_default_color() {
+    assert!(is_default_color(&["bg", "red", "500"]));
+    assert!(is_default_color(&["border", "x", "white"]));
+    assert!(!is_default_color(&["bg", "bleu", "500"]));
+    assert!(!is_default_color(&["bg", "red", "550"]));
+    assert!(!is_default_color(&["text", "primary"]));
+}
@@ -1,8 +1,39 @@
-use super::validators;
+use crate::validators;
+use crate::MergeTheme;
 
 pub type Result<T> = std::result::Result<T, &'static str>;
 
-pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static str> {
+/// Returned for classes that don't match any known utility.
+pub const UNKNOWN_CLASS: &str = "Invalid Tailwind class";
+
+/// The color utilities that take a `/` opacity modifier, so they're a color whatever their value (e.g. `bg-brand/50`).
+///
+/// `text` is not included, as `text-lg/7` is a font size with a line height.
+pub fn color_with_opacity(classes: &[&str], postfix: Option<&str>) -> Option<&'static str> {
+    let postfix = postfix?;
+    // `bg-linear-to-r/srgb` is an interpolation mode, not an opacity.
+    let is_opacity = postfix.starts_with('[')
+        || postfix.starts_with('(')
+        || postfix.chars().all(|c| c.is_ascii_digit() || c == '.');
+    if !is_opacity {
+        return None;
+    }
+    match classes {
+        ["bg", ..] => Some("background-color"),
+        ["decoration", ..] => Some("text-decoration-color"),
+        ["placeholder", ..] => Some("placeholder-color"),
+        _ => None,
+    }
+}
+
+pub fn get_collision_id(
+    classes: &[&str],
+    arbitrary: &str,
+    theme: &MergeTheme,
+) -> Result<&'static str> {
+    if let Some(collision_id) = theme.collision_id(classes) {
+        return Ok(collision_id);
+    }
     match classes {
         // https://tailwindcss.com/docs/aspect-ratio
         ["aspect", "auto" | "square" | "video"] => Ok("aspect"),
@@ -231,7 +262,7 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
 
         // https://tailwindcss.com/docs/justify-content
         ["justify", "normal" | "start" | "end" | "center" | "between" | "around" | "evenly" | "stretch"] => Ok("justify-content"),
-        
+
         // https://tailwindcss.com/docs/justify-items
         ["justify", "items", "start" | "end" | "center" | "stretch"] => Ok("justify-items"),
 
@@ -246,7 +277,7 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
 
         // https://tailwindcss.com/docs/align-self
         ["self", "auto" | "start" | "end" | "center" | "stretch" | "baseline"] => Ok("align-self"),
-        
+
         // https://tailwindcss.com/docs/place-content
         ["place", "content", "center" | "start" | "end" | "between" | "around" | "evenly" | "baseline" | "stretch"] => Ok("place-content"),
 
@@ -322,6 +353,9 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
         ["text", rest] if valid_text_size(rest) => Ok("font-size"),
         ["text"] if is_arbitrary_len(arbitrary) => Ok("font-size"),
 
+        // https://v3.tailwindcss.com/docs/text-color#changing-the-opacity
+        ["text", "opacity", ..] => Ok("text-opacity"),
+
         // https://tailwindcss.com/docs/text-color
         ["text", ..] => Ok("text-color"),
 
@@ -455,6 +489,9 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
         // TODO: plus-lighter not valid
         ["bg", "blend", mode @ ..] if valid_blend(mode) => Ok("background-blend-mode"),
 
+        // https://v3.tailwindcss.com/docs/background-color#changing-the-opacity
+        ["bg", "opacity", ..] => Ok("bg-opacity"),
+
         // https://tailwindcss.com/docs/background-color
         ["bg", ..] => Ok("background-color"),
 
@@ -515,6 +552,9 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
         ["border", "spacing", "y", ..] => Ok("border-spacing-y"),
         ["border", "spacing", ..] => Ok("border-spacing"),
 
+        // https://v3.tailwindcss.com/docs/border-color#changing-the-opacity
+        ["border", "opacity", ..] => Ok("border-opacity"),
+
         // https://tailwindcss.com/docs/border-color
         ["border", "t", ..] => Ok("border-color-t"),
         ["border", "r", ..] => Ok("border-color-r"),
@@ -535,9 +575,16 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
         ["divide", "x", ..] => Ok("divide-x"),
         ["divide", "y", ..] => Ok("divide-y"),
 
+        // https://v3.tailwindcss.com/docs/divide-color#changing-the-opacity
+        ["divide", "opacity", ..] => Ok("divide-opacity"),
+
         // https://tailwindcss.com/docs/divide-color
         ["divide", ..] => Ok("divide-color"),
 
+        // https://v3.tailwindcss.com/docs/placeholder-color
+        ["placeholder", "opacity", ..] => Ok("placeholder-opacity"),
+        ["placeholder", ..] => Ok("placeholder-color"),
+
         // https://tailwindcss.com/docs/outline-style
         | ["outline"]
         | ["outline", "none" | "solid" | "dashed" | "dotted" | "double"] 
@@ -568,6 +615,9 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
 
         // https://tailwindcss.com/docs/ring-offset-color
         ["ring", "offset", ..] => Ok("ring-offset-color"),
+
+        // https://v3.tailwindcss.com/docs/ring-color#changing-the-opacity
+        ["ring", "opacity", ..] => Ok("ring-opacity"),
         ["ring", ..] => Ok("ring-color"),
 
 
@@ -685,7 +735,7 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
 
         // https://tailwindcss.com/docs/transform-origin
         ["origin", ..] => Ok("transform-origin"),
-        
+
         // https://tailwindcss.com/docs/accent-color
         ["accent", ..] => Ok("accent-color"),
 
@@ -754,7 +804,7 @@ pub fn get_collision_id(classes: &[&str], arbitrary: &str) -> Result<&'static st
         // https://tailwindcss.com/docs/forced-color-adjust
         ["forced", "color", "adjust", "auto" | "none"] => Ok("forced-color-adjust"),
 
-        _ => Err("Invalid Tailwind class"),
+        _ => Err(UNKNOWN_CLASS),
     }
 }
 
@@ -841,7 +891,7 @@ fn parse_fraction(input: &str) -> Option<(usize, usize)> {
     Some((a, b))
 }
 
-fn is_t_shirt_size(input: &str) -> bool {
+pub(crate) fn is_t_shirt_size(input: &str) -> bool {
     input.ends_with("xs")
         || input.ends_with("sm")
         || input.ends_with("md")
@@ -861,7 +911,7 @@ fn is_arbitrary_value(input: &str) -> bool {
     validators::arbitrary::parse(input).is_ok()
 }
 
-fn is_arbitrary_len(input: &str) -> bool {
+pub(crate) fn is_arbitrary_len(input: &str) -> bool {
     is_valid_arbitrary_value(input, |label| label == "length", is_valid_length)
 }
 
@@ -909,61 +959,69 @@ mod test {
 
     #[test]
     fn parse_stroke() {
-        let result = get_collision_id(&["stroke"], "10px");
+        let result = get_collision_id(&["stroke"], "10px", &MergeTheme::default());
         assert_eq!(result, Ok("stroke-width"));
 
-        let result = get_collision_id(&["stroke"], "hsl(350_80%_0%)");
+        let result = get_collision_id(&["stroke"], "hsl(350_80%_0%)", &MergeTheme::default());
         assert_eq!(result, Ok("stroke"));
     }
 
     #[test]
     fn parse_margin() {
-        let result = get_collision_id(&["my", "2"], "");
+        let result = get_collision_id(&["my", "2"], "", &MergeTheme::default());
         assert_eq!(result, Ok("margin-y"));
 
-        let result = get_collision_id(&["m", "2"], "");
+        let result = get_collision_id(&["m", "2"], "", &MergeTheme::default());
         assert_eq!(result, Ok("margin"));
 
-        let result = get_collision_id(&["m"], "2px");
+        let result = get_collision_id(&["m"], "2px", &MergeTheme::default());
         assert_eq!(result, Ok("margin"));
 
-        let result = get_collision_id(&["my"], "10rem");
+        let result = get_collision_id(&["my"], "10rem", &MergeTheme::default());
         assert_eq!(result, Ok("margin-y"));
     }
 
     #[test]
     fn parse_inset() {
-        let result = get_collision_id(&["inset", "auto"], "");
+        let result = get_collision_id(&["inset", "auto"], "", &MergeTheme::default());
         assert_eq!(result, Ok("inset"));
 
-        let result = get_collision_id(&["inset", "0"], "");
+        let result = get_collision_id(&["inset", "0"], "", &MergeTheme::default());
         assert_eq!(result, Ok("inset"));
 
-        let result = get_collision_id(&["inset"], "10px");
+        let result = get_collision_id(&["inset"], "10px", &MergeTheme::default());
         assert_eq!(result, Ok("inset"));
     }
 
     #[test]
     fn parse_len() {
         assert!(is_valid_length("calc(theme(fontSize.4xl)/1.125)"));
-        let result = get_collision_id(&["text"], "length:theme(someScale.someValue)");
+        let result = get_collision_id(
+            &["text"],
+            "length:theme(someScale.someValue)",
+            &MergeTheme::default(),
+        );
         assert_eq!(result, Ok("font-size"));
 
         assert!(is_valid_length("calc(theme(fontSize.4xl)/1.125)"));
-        let result = get_collision_id(&["text"], "calc(theme(fontSize.4xl)/1.125)");
+        let result = get_collision_id(
+            &["text"],
+            "calc(theme(fontSize.4xl)/1.125)",
+            &MergeTheme::default(),
+        );
         assert_eq!(result, Ok("font-size"));
     }
 
     #[test]
     fn parse_text_color() {
         assert!(!is_arbitrary_len("color:0"), "shouldn't be a length");
-        let result = get_collision_id(&["text"], "color:0");
+        let result = get_collision_id(&["text"], "color:0", &MergeTheme::default());
         assert_eq!(result, Ok("text-color"));
     }
 
     #[test]
     fn parse_margin_arb() {
-        let result = get_collision_id(&["m"], "length:var(--c)");
+        let result = get_collision_id(&["m"], "length:var(--c)", &MergeTheme::default());
         assert_eq!(result, Ok("margin"));
     }
 
@@ -972,13 +1030,17 @@ mod test {
         assert!(!is_arbitrary_len(
             "color:rgb(var(--color-gray-500-rgb)/50%)"
         ));
-        let result = get_collision_id(&["border"], "color:rgb(var(--color-gray-500-rgb)/50%)");
+        let result = get_collision_id(
+            &["border"],
+            "color:rgb(var(--color-gray-500-rgb)/50%)",
+            &MergeTheme::default(),
+        );
         assert_eq!(result, Ok("border-color"));
 
-        let result = get_collision_id(&["border", "some", "color"], "");
+        let result = get_collision_id(&["border", "some", "color"], "", &MergeTheme::default());
         assert_eq!(result, Ok("border-color"));
 
-        let result = get_collision_id(&["border", "b"], "");
+        let result = get_collision_id(&["border", "b"], "", &MergeTheme::default());
         assert_eq!(result, Ok("border-w-b"));
     }
 }
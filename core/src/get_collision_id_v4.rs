@@ -0,0 +1,220 @@
+use crate::get_collision_id::{
+    get_collision_id as get_collision_id_v3, is_arbitrary_len, is_t_shirt_size, Result,
+};
+use crate::MergeTheme;
+
+/// Tailwind v4 utilities, falling back to the v3 rules for everything that is unchanged.
+///
+/// <https://tailwindcss.com/docs/upgrade-guide#changes-from-v3>
+pub fn get_collision_id(
+    classes: &[&str],
+    arbitrary: &str,
+    theme: &MergeTheme,
+) -> Result<&'static str> {
+    if let Some(collision_id) = theme.collision_id(classes) {
+        return Ok(collision_id);
+    }
+    match classes {
+        // https://tailwindcss.com/docs/align-items
+        ["items", "baseline", "last"] => Ok("align-items"),
+        ["items", "center" | "end", "safe"] => Ok("align-items"),
+
+        // https://tailwindcss.com/docs/align-self
+        ["self", "baseline", "last"] => Ok("align-self"),
+        ["self", "center" | "end", "safe"] => Ok("align-self"),
+
+        // https://tailwindcss.com/docs/justify-content
+        ["justify", "center" | "end", "safe"] => Ok("justify-content"),
+
+        // https://tailwindcss.com/docs/place-items
+        ["place", "items", "center" | "end", "safe"] => Ok("place-items"),
+
+        // https://tailwindcss.com/docs/place-self
+        ["place", "self", "center" | "end", "safe"] => Ok("place-self"),
+
+        // https://tailwindcss.com/docs/z-index
+        ["z"] if is_css_variable(arbitrary) => Ok("z-index"),
+
+        // https://tailwindcss.com/docs/order
+        ["order"] if is_css_variable(arbitrary) => Ok("order"),
+
+        // https://tailwindcss.com/docs/flex-basis
+        ["basis"] if is_css_variable(arbitrary) => Ok("flex-basis"),
+
+        // https://tailwindcss.com/docs/overflow-wrap
+        ["wrap", "break", "word"] | ["wrap", "anywhere" | "normal"] => Ok("overflow-wrap"),
+
+        // https://tailwindcss.com/docs/font-stretch
+        ["font", "stretch", ..] => Ok("font-stretch"),
+
+        // https://tailwindcss.com/docs/text-shadow
+        ["text", "shadow"] if arbitrary.is_empty() => Ok("text-shadow"),
+        ["text", "shadow", "none"] => Ok("text-shadow"),
+        ["text", "shadow", size] if is_t_shirt_size(size) => Ok("text-shadow"),
+        ["text", "shadow", ..] => Ok("text-shadow-color"),
+
+        // https://tailwindcss.com/docs/background-image
+        ["bg", "linear" | "radial" | "conic", ..] => Ok("background-image"),
+
+        // https://tailwindcss.com/docs/outline-style
+        ["outline", "hidden"] => Ok("outline-style"),
+
+        // https://tailwindcss.com/docs/box-shadow#adding-an-inset-shadow
+        ["inset", "shadow"] if arbitrary.is_empty() => Ok("inset-shadow"),
+        ["inset", "shadow", "none"] => Ok("inset-shadow"),
+        ["inset", "shadow", size] if is_t_shirt_size(size) => Ok("inset-shadow"),
+        ["inset", "shadow", ..] => Ok("inset-shadow-color"),
+
+        // https://tailwindcss.com/docs/box-shadow#adding-an-inset-ring
+        ["inset", "ring"] if arbitrary.is_empty() || is_arbitrary_len(arbitrary) => {
+            Ok("inset-ring-width")
+        }
+        ["inset", "ring", rest] if rest.parse::<usize>().is_ok() => Ok("inset-ring-width"),
+        ["inset", "ring", ..] => Ok("inset-ring-color"),
+
+        // https://tailwindcss.com/docs/box-shadow#setting-the-ring-width
+        ["ring"] if arbitrary.is_empty() => Ok("ring-width"),
+
+        // https://tailwindcss.com/docs/filter-drop-shadow
+        ["drop", "shadow"] if arbitrary.is_empty() => Ok("drop-shadow"),
+        ["drop", "shadow", "none"] => Ok("drop-shadow"),
+        ["drop", "shadow", size] if is_t_shirt_size(size) => Ok("drop-shadow"),
+        ["drop", "shadow", ..] => Ok("drop-shadow-color"),
+
+        // https://tailwindcss.com/docs/opacity
+        ["opacity"] if is_css_variable(arbitrary) => Ok("opacity"),
+
+        // https://tailwindcss.com/docs/transition-duration
+        ["duration"] if is_css_variable(arbitrary) => Ok("transition-duration"),
+
+        // https://tailwindcss.com/docs/transition-delay
+        ["delay"] if is_css_variable(arbitrary) => Ok("transition-delay"),
+
+        // https://tailwindcss.com/docs/backface-visibility
+        ["backface", "visible" | "hidden"] => Ok("backface-visibility"),
+
+        // https://tailwindcss.com/docs/perspective-origin
+        ["perspective", "origin", ..] => Ok("perspective-origin"),
+
+        // https://tailwindcss.com/docs/perspective
+        ["perspective", ..] => Ok("perspective"),
+
+        // https://tailwindcss.com/docs/rotate
+        ["rotate", "x", ..] => Ok("rotate-x"),
+        ["rotate", "y", ..] => Ok("rotate-y"),
+        ["rotate", "z", ..] => Ok("rotate-z"),
+        ["rotate", "none"] => Ok("rotate"),
+        ["rotate"] if is_css_variable(arbitrary) => Ok("rotate"),
+
+        // https://tailwindcss.com/docs/scale
+        ["scale", "z", ..] => Ok("scale-z"),
+        ["scale", "3d" | "none"] => Ok("scale"),
+        ["scale", "x"] if is_css_variable(arbitrary) => Ok("scale-x"),
+        ["scale", "y"] if is_css_variable(arbitrary) => Ok("scale-y"),
+        ["scale"] if is_css_variable(arbitrary) => Ok("scale"),
+
+        // https://tailwindcss.com/docs/translate
+        ["translate", "z", ..] => Ok("translate-z"),
+        ["translate", "x" | "y", ..] => get_collision_id_v3(classes, arbitrary, theme),
+        ["translate", ..] => Ok("translate"),
+
+        // https://tailwindcss.com/docs/transform-style
+        ["transform", "3d" | "flat"] => Ok("transform-style"),
+
+        // https://tailwindcss.com/docs/color-scheme
+        ["scheme", "normal" | "dark" | "light"]
+        | ["scheme", "light", "dark"]
+        | ["scheme", "only", "dark" | "light"] => Ok("color-scheme"),
+
+        // https://tailwindcss.com/docs/field-sizing
+        ["field", "sizing", "fixed" | "content"] => Ok("field-sizing"),
+
+        _ => get_collision_id_v3(classes, arbitrary, theme),
+    }
+}
+
+// `(--brand)` and `[--brand]` are both shorthand for `var(--brand)`
+fn is_css_variable(input: &str) -> bool {
+    input.starts_with("--")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_shadows() {
+        assert_eq!(
+            get_collision_id(&["inset", "shadow", "sm"], "", &MergeTheme::default()),
+            Ok("inset-shadow")
+        );
+        assert_eq!(
+            get_collision_id(
+                &["inset", "shadow", "red", "500"],
+                "",
+                &MergeTheme::default()
+            ),
+            Ok("inset-shadow-color")
+        );
+        assert_eq!(
+            get_collision_id(&["inset", "ring", "2"], "", &MergeTheme::default()),
+            Ok("inset-ring-width")
+        );
+        assert_eq!(
+            get_collision_id(&["inset", "0"], "", &MergeTheme::default()),
+            Ok("inset")
+        );
+        assert_eq!(
+            get_collision_id(&["text", "shadow", "lg"], "", &MergeTheme::default()),
+            Ok("text-shadow")
+        );
+        assert_eq!(
+            get_collision_id(&["text", "lg"], "", &MergeTheme::default()),
+            Ok("font-size")
+        );
+    }
+
+    #[test]
+    fn parse_css_variable() {
+        assert_eq!(
+            get_collision_id(&["z"], "--layer", &MergeTheme::default()),
+            Ok("z-index")
+        );
+        assert_eq!(
+            get_collision_id(&["bg"], "--brand", &MergeTheme::default()),
+            Ok("background-color")
+        );
+        assert_eq!(
+            get_collision_id(&["text"], "length:--size", &MergeTheme::default()),
+            Ok("font-size")
+        );
+        assert_eq!(
+            get_collision_id(&["scale", "x"], "--s", &MergeTheme::default()),
+            Ok("scale-x")
+        );
+    }
+
+    #[test]
+    fn parse_transforms() {
+        assert_eq!(
+            get_collision_id(&["rotate", "x", "45"], "", &MergeTheme::default()),
+            Ok("rotate-x")
+        );
+        assert_eq!(
+            get_collision_id(&["rotate", "45"], "", &MergeTheme::default()),
+            Ok("rotate")
+        );
+        assert_eq!(
+            get_collision_id(&["translate", "4"], "", &MergeTheme::default()),
+            Ok("translate")
+        );
+        assert_eq!(
+            get_collision_id(&["translate", "x", "4"], "", &MergeTheme::default()),
+            Ok("translate-x")
+        );
+        assert_eq!(
+            get_collision_id(&["translate", "z", "4"], "", &MergeTheme::default()),
+            Ok("translate-z")
+        );
+    }
+}
@@ -1,4 +1,4 @@
-pub(crate) fn get_collisions(collision_id: &str) -> Option<Vec<&'static str>> {
+pub fn get_collisions(collision_id: &str) -> Option<Vec<&'static str>> {
     match collision_id {
         "overflow" => Some(vec!["overflow-x", "overflow-y"]),
         "overscroll" => Some(vec!["overscroll-x", "overscroll-y"]),
@@ -117,6 +117,7 @@ pub(crate) fn get_collisions(collision_id: &str) -> Option<Vec<&'static str>> {
         "touch-x" => Some(vec!["touch"]),
         "touch-y" => Some(vec!["touch"]),
         "touch-pz" => Some(vec!["touch"]),
+        "translate" => Some(vec!["translate-x", "translate-y"]),
         _ => None,
     }
 }
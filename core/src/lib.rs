@@ -0,0 +1,35 @@
+//! Tailwind class parser and classifier shared by [`tailwind_fuse`](https://docs.rs/tailwind_fuse) and its macros.
+//!
+//! This is an implementation detail, use `tailwind_fuse` instead.
+
+/// Parses Tailwind classes into their variants, elements and values.
+pub mod ast;
+/// The default Tailwind color palette.
+pub mod colors;
+/// Tailwind v3 CollisionIds.
+pub mod get_collision_id;
+/// Tailwind v4 CollisionIds.
+pub mod get_collision_id_v4;
+/// CollisionIds that conflict with each other.
+pub mod get_collisions;
+/// Resolves conflicts between Tailwind classes.
+pub mod merge;
+mod theme;
+mod validators;
+
+pub use theme::{MergeTheme, ThemeSection, THEME_UTILITIES};
+
+/// Tailwind major version, which decides the class syntax and utilities that are understood.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TailwindVersion {
+    /// <https://v3.tailwindcss.com/docs>
+    #[default]
+    V3,
+    /// Adds CSS variable shorthand (`bg-(--brand)`), trailing important (`bg-red-500!`),
+    /// variant-style prefix (`tw:flex`) and the new v4 utilities.
+    ///
+    /// When a prefix is set, it is written without a trailing dash (e.g. `tw`).
+    ///
+    /// <https://tailwindcss.com/docs/upgrade-guide#changes-from-v3>
+    V4,
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use crate::ast::{AstParseOptions, AstStyle};
+use crate::get_collision_id::color_with_opacity;
+use crate::{MergeTheme, TailwindVersion};
+
+/// How a single class was resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassExplanation<'a> {
+    /// The class as written
+    pub class: &'a str,
+    /// The parsed class, `None` if it could not be parsed
+    pub style: Option<AstStyle<'a>>,
+    /// The CollisionId of the class, or why it could not be found.
+    ///
+    /// Classes without a CollisionId are always kept.
+    pub collision_id: Result<&'a str, &'static str>,
+    /// Index of the later class that removed this one
+    pub removed_by: Option<usize>,
+}
+
+impl ClassExplanation<'_> {
+    /// Returns true if the class is part of the merged result.
+    pub fn is_kept(&self) -> bool {
+        self.removed_by.is_none()
+    }
+}
+
+/// Returned for classes that can't be parsed.
+pub const PARSE_ERROR: &str = "Failed to parse class";
+
+/// The default collision id rules for the Tailwind version.
+pub fn collision_id_table(
+    version: TailwindVersion,
+) -> fn(&[&str], &str, &MergeTheme) -> crate::get_collision_id::Result<&'static str> {
+    match version {
+        TailwindVersion::V3 => crate::get_collision_id::get_collision_id,
+        TailwindVersion::V4 => crate::get_collision_id_v4::get_collision_id,
+    }
+}
+
+/// Merges all the Tailwind classes, resolving conflicts.
+pub fn merge<'a>(
+    class: &[&'a str],
+    options: AstParseOptions<'a>,
+    theme: &MergeTheme,
+    collision_id_fn: impl Fn(&[&str], Option<&str>, Option<&str>) -> Option<&'static str>,
+    collisions_fn: impl Fn(&str) -> Option<Vec<&'a str>>,
+) -> String {
+    resolve(class, options, theme, collision_id_fn, collisions_fn)
+        .into_iter()
+        .filter(ClassExplanation::is_kept)
+        .map(|explanation| explanation.class)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Resolves which classes are kept, and why.
+pub fn resolve<'a>(
+    class: &[&'a str],
+    options: AstParseOptions<'a>,
+    theme: &MergeTheme,
+    collision_id_fn: impl Fn(&[&str], Option<&str>, Option<&str>) -> Option<&'static str>,
+    collisions_fn: impl Fn(&str) -> Option<Vec<&'a str>>,
+) -> Vec<ClassExplanation<'a>> {
+    let get_collision_id = collision_id_table(options.version);
+
+    let styles: Vec<Result<AstStyle, &str>> = crate::ast::parse_tailwind(class, options);
+    let len = styles.len();
+
+    let mut explanations: Vec<ClassExplanation> = Vec::with_capacity(len);
+    // Collision -> index of the class that claimed it
+    let mut collision_styles: HashMap<Collision, usize> = HashMap::new();
+
+    for (index, style) in styles.into_iter().enumerate().rev() {
+        let style = match style {
+            Ok(style) => style,
+            Err(s) => {
+                explanations.push(ClassExplanation {
+                    class: s,
+                    style: None,
+                    collision_id: Err(PARSE_ERROR),
+                    removed_by: None,
+                });
+                continue;
+            }
+        };
+
+        let find_collision_id = |elements: &[&str], postfix: Option<&str>| {
+            collision_id_fn(elements, style.arbitrary, postfix)
+                .or_else(|| color_with_opacity(elements, postfix))
+                .map(Ok)
+                .unwrap_or_else(|| {
+                    let arbitrary = style.arbitrary.unwrap_or_default();
+                    get_collision_id(elements, arbitrary, theme)
+                })
+        };
+
+        // Try without the postfix modifier first, then with the postfix as part of the value (e.g. `aspect-16/9`)
+        let result = match (
+            find_collision_id(&style.elements, style.postfix),
+            style.elements_with_postfix(),
+        ) {
+            (Err(_), Some(elements)) => find_collision_id(&elements, None),
+            (result, _) => result,
+        };
+
+        let mut explanation = ClassExplanation {
+            class: style.source,
+            style: None,
+            collision_id: result,
+            removed_by: None,
+        };
+
+        match result {
+            Err(error) => match Collision::check_arbitrary(style.clone()) {
+                Some(collision) => {
+                    explanation.collision_id = Ok(collision.collision_id);
+                    explanation.removed_by = collision_styles.get(&collision).copied();
+                    collision_styles.entry(collision).or_insert(index);
+                }
+                None => {
+                    #[cfg(feature = "debug")]
+                    println!("No Instance found: {style:?} {error:?}");
+                    let _ = error;
+                }
+            },
+            Ok(collision_id) => {
+                // hover:md:focus
+                let all_variants: Vec<&str> = sort_variants(style.variants.clone());
+
+                let collision = Collision {
+                    important: style.important,
+                    variants: all_variants.clone(),
+                    collision_id,
+                };
+
+                if let Some(removed_by) = collision_styles.get(&collision) {
+                    explanation.removed_by = Some(*removed_by);
+                } else {
+                    // Add the current collision_id.
+                    collision_styles.insert(collision, index);
+
+                    // `font-size` always overrides `line-height`, so `text-lg/7` needs no extra rule.
+                    if let Some(collisions) = collisions_fn(collision_id) {
+                        collisions.into_iter().for_each(|collision_id| {
+                            let collision = Collision {
+                                important: style.important,
+                                variants: all_variants.clone(),
+                                collision_id,
+                            };
+
+                            collision_styles.entry(collision).or_insert(index);
+                        });
+                    }
+                }
+            }
+        }
+
+        explanation.style = Some(style);
+        explanations.push(explanation);
+    }
+
+    explanations.reverse();
+    explanations
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Collision<'a> {
+    important: bool,
+    variants: Vec<&'a str>,
+    collision_id: &'a str,
+}
+
+// For [color:blue] => label = "color"
+impl<'a> Collision<'a> {
+    fn check_arbitrary(style: AstStyle<'a>) -> Option<Self> {
+        let arbitrary = style.arbitrary?;
+        let index = arbitrary.find(':')?;
+        let (collision_id, _) = arbitrary.split_at(index);
+        Some(Self {
+            collision_id,
+            important: style.important,
+            variants: sort_variants(style.variants),
+        })
+    }
+}
+
+// `focus:hover` and `hover:focus` are the same, but arbitrary variants (e.g. `[&>*]`) depend on their position.
+fn sort_variants(mut variants: Vec<&str>) -> Vec<&str> {
+    let mut start = 0;
+    for index in 0..=variants.len() {
+        if index == variants.len() || variants[index].starts_with('[') {
+            variants[start..index].sort_unstable();
+            start = index + 1;
+        }
+    }
+    variants
+}
+
+#[test]
+fn check_sort_variants() {
+    assert_eq!(sort_variants(vec!["hover", "focus"]), ["focus", "hover"]);
+    assert_eq!(
+        sort_variants(vec!["hover", "focus", "[&>*]", "md", "dark"]),
+        ["focus", "hover", "[&>*]", "dark", "md"]
+    );
+    assert_eq!(sort_variants(vec!["[&>*]", "hover"]), ["[&>*]", "hover"]);
+    assert_eq!(sort_variants(vec![]), Vec::<&str>::new());
+}
+
+#[test]
+fn check_arbitrary() {
+    let style = crate::ast::parse_tailwind(&["[color:blue]"], Default::default())
+        .into_iter()
+        .next()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        Collision::check_arbitrary(style),
+        Some(Collision {
+            important: false,
+            variants: vec![],
+            collision_id: "color"
+        })
+    );
+}
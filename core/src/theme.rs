@@ -0,0 +1,245 @@
+/// A section of [`MergeTheme`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ThemeSection {
+    /// `theme.colors`
+    Colors,
+    /// `theme.spacing`
+    Spacing,
+    /// `theme.fontSize`
+    FontSize,
+    /// `theme.fontFamily`
+    FontFamily,
+    /// `theme.boxShadow`
+    BoxShadow,
+}
+
+/// Utilities that take a theme key, with the section of the key and their collision id.
+///
+/// Used both when merging with a [`MergeTheme`] and when generating code from a Tailwind config,
+/// so both merge the same way. Earlier entries win, e.g. `text-body` is a font size when `body`
+/// is both a font size and a color.
+pub const THEME_UTILITIES: &[(&str, ThemeSection, &str)] = &[
+    ("text", ThemeSection::FontSize, "font-size"),
+    ("font", ThemeSection::FontFamily, "font-family"),
+    ("shadow", ThemeSection::BoxShadow, "box-shadow"),
+    ("bg", ThemeSection::Colors, "background-color"),
+    ("text", ThemeSection::Colors, "text-color"),
+    ("decoration", ThemeSection::Colors, "text-decoration-color"),
+    ("border", ThemeSection::Colors, "border-color"),
+    ("divide", ThemeSection::Colors, "divide-color"),
+    ("outline", ThemeSection::Colors, "outline-color"),
+    ("ring", ThemeSection::Colors, "ring-color"),
+    ("shadow", ThemeSection::Colors, "box-shadow-color"),
+    ("accent", ThemeSection::Colors, "accent-color"),
+    ("caret", ThemeSection::Colors, "caret-color"),
+    ("fill", ThemeSection::Colors, "fill"),
+    ("stroke", ThemeSection::Colors, "stroke"),
+    ("from", ThemeSection::Colors, "from"),
+    ("via", ThemeSection::Colors, "via"),
+    ("to", ThemeSection::Colors, "to"),
+    ("basis", ThemeSection::Spacing, "flex-basis"),
+    ("inset-x", ThemeSection::Spacing, "inset-x"),
+    ("inset-y", ThemeSection::Spacing, "inset-y"),
+    ("inset", ThemeSection::Spacing, "inset"),
+    ("top", ThemeSection::Spacing, "top"),
+    ("right", ThemeSection::Spacing, "right"),
+    ("bottom", ThemeSection::Spacing, "bottom"),
+    ("left", ThemeSection::Spacing, "left"),
+    ("start", ThemeSection::Spacing, "start"),
+    ("end", ThemeSection::Spacing, "end"),
+];
+
+/// Custom theme keys from your Tailwind config, used to classify classes that can't be told apart by name alone.
+///
+/// e.g. without a theme, `text-body` is assumed to be a color. Registering `body` as a font size makes it a font size.
+///
+/// Keys are written as they appear in the class, without the utility (e.g. `primary-foreground` for `bg-primary-foreground`).
+///
+/// <https://tailwindcss.com/docs/theme>
+///
+/// ## Example
+///
+/// ```
+/// # use tailwind_fuse_core::{ast::AstParseOptions, merge::merge, MergeTheme};
+/// let theme = MergeTheme::new()
+///     .font_size(["body", "caption"])
+///     .colors(["primary", "ring"]);
+/// let tw_merge = |class| merge(&[class], AstParseOptions::default(), &theme, |_, _, _| None, |_| None);
+///
+/// assert_eq!("text-red-500 text-body", tw_merge("text-red-500 text-body"));
+/// assert_eq!("text-caption", tw_merge("text-body text-caption"));
+/// assert_eq!("text-primary", tw_merge("text-red-500 text-primary"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeTheme {
+    colors: Vec<String>,
+    spacing: Vec<String>,
+    font_size: Vec<String>,
+    font_family: Vec<String>,
+    box_shadow: Vec<String>,
+}
+
+impl MergeTheme {
+    /// Create an empty theme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `theme.colors` keys (e.g. `primary` for `bg-primary`, `primary-foreground` for `text-primary-foreground`)
+    pub fn colors<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.colors.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add `theme.spacing` keys (e.g. `gutter` for `top-gutter`)
+    pub fn spacing<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.spacing.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add `theme.fontSize` keys (e.g. `body` for `text-body`)
+    pub fn font_size<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.font_size.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add `theme.fontFamily` keys (e.g. `display` for `font-display`)
+    pub fn font_family<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.font_family.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add `theme.boxShadow` keys (e.g. `card` for `shadow-card`)
+    pub fn box_shadow<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.box_shadow.extend(keys.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns true if no keys have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+            && self.spacing.is_empty()
+            && self.font_size.is_empty()
+            && self.font_family.is_empty()
+            && self.box_shadow.is_empty()
+    }
+
+    /// Registered `theme.colors` keys
+    pub fn get_colors(&self) -> &[String] {
+        &self.colors
+    }
+
+    /// Registered `theme.spacing` keys
+    pub fn get_spacing(&self) -> &[String] {
+        &self.spacing
+    }
+
+    /// Registered `theme.fontSize` keys
+    pub fn get_font_size(&self) -> &[String] {
+        &self.font_size
+    }
+
+    /// Registered `theme.fontFamily` keys
+    pub fn get_font_family(&self) -> &[String] {
+        &self.font_family
+    }
+
+    /// Registered `theme.boxShadow` keys
+    pub fn get_box_shadow(&self) -> &[String] {
+        &self.box_shadow
+    }
+
+    /// Keys of a theme section.
+    pub fn get_keys(&self, section: ThemeSection) -> &[String] {
+        match section {
+            ThemeSection::Colors => &self.colors,
+            ThemeSection::Spacing => &self.spacing,
+            ThemeSection::FontSize => &self.font_size,
+            ThemeSection::FontFamily => &self.font_family,
+            ThemeSection::BoxShadow => &self.box_shadow,
+        }
+    }
+
+    /// The collision id of a class that uses a registered key, following [`THEME_UTILITIES`].
+    pub fn collision_id(&self, elements: &[&str]) -> Option<&'static str> {
+        if self.is_empty() {
+            return None;
+        }
+        THEME_UTILITIES
+            .iter()
+            .find_map(|&(utility, section, collision_id)| {
+                let len = utility.split('-').count();
+                let matches = elements.len() > len
+                    && utility.split('-').eq(elements[..len].iter().copied())
+                    && contains(self.get_keys(section), &elements[len..]);
+                matches.then_some(collision_id)
+            })
+    }
+}
+
+// Compares `primary-foreground` with `["primary", "foreground"]` without allocating.
+fn contains(keys: &[String], elements: &[&str]) -> bool {
+    if elements.is_empty() {
+        return false;
+    }
+    keys.iter().any(|key| {
+        let mut parts = key.split('-');
+        elements
+            .iter()
+            .all(|element| parts.next() == Some(*element))
+            && parts.next().is_none()
+    })
+}
+
+#[test]
+fn test_contains() {
+    let keys = vec!["primary".to_string(), "primary-foreground".to_string()];
+    assert!(contains(&keys, &["primary"]));
+    assert!(contains(&keys, &["primary", "foreground"]));
+    assert!(!contains(&keys, &["primary", "background"]));
+    assert!(!contains(&keys, &["foreground"]));
+    assert!(!contains(&keys, &[]));
+}
+
+#[test]
+fn test_collision_id() {
+    let theme = MergeTheme::new()
+        .font_size(["body"])
+        .colors(["body", "primary-foreground"])
+        .spacing(["gutter"]);
+    assert_eq!(theme.collision_id(&["text", "body"]), Some("font-size"));
+    assert_eq!(
+        theme.collision_id(&["bg", "body"]),
+        Some("background-color")
+    );
+    assert_eq!(
+        theme.collision_id(&["ring", "primary", "foreground"]),
+        Some("ring-color")
+    );
+    assert_eq!(
+        theme.collision_id(&["inset", "x", "gutter"]),
+        Some("inset-x")
+    );
+    assert_eq!(theme.collision_id(&["bg"]), None);
+    assert_eq!(theme.collision_id(&["bg", "red", "500"]), None);
+}
@@ -1,11 +1,13 @@
-use leptos::*;
+use leptos::prelude::*;
 use leptos_meta::*;
-use leptos_router::*;
-use leptos_theme::*;
+use leptos_router::components::{Route, Router, Routes};
+use leptos_router::hooks::use_params_map;
+use leptos_router::path;
 
 use crate::component::badge::*;
 use crate::component::button::*;
 use crate::component::card::*;
+use crate::theme::*;
 
 use tailwind_fuse::*;
 
@@ -19,9 +21,9 @@ pub fn App() -> impl IntoView {
         <main>
             <ThemeProvider>
                 <Router>
-                    <Routes>
-                        <Route path="/" view=HomePage/>
-                        <Route path="/:else" view=ErrorPage/>
+                    <Routes fallback=ErrorPage>
+                        <Route path=path!("/") view=HomePage/>
+                        <Route path=path!("/:else") view=ErrorPage/>
                     </Routes>
                 </Router>
             </ThemeProvider>
@@ -31,7 +33,7 @@ pub fn App() -> impl IntoView {
 
 #[component]
 fn HomePage() -> impl IntoView {
-    let toggle_default = create_rw_signal(false);
+    let toggle_default = RwSignal::new(false);
 
     let buttons = vec![
         (
@@ -44,7 +46,7 @@ fn HomePage() -> impl IntoView {
                     <Button size=ButtonSize::Icon>Icon</Button>
                 </div>
             }
-            .into_view(),
+            .into_any(),
         ),
         (
             "Button variants",
@@ -58,7 +60,7 @@ fn HomePage() -> impl IntoView {
                     <Button variant=ButtonVariant::Link>Link</Button>
                 </div>
             }
-            .into_view(),
+            .into_any(),
         ),
         (
             "Conditionally change the button variant",
@@ -71,7 +73,8 @@ fn HomePage() -> impl IntoView {
                 >
                     Click to change variant
                 </Button>
-            },
+            }
+            .into_any(),
         ),
         (
             "Badge variants",
@@ -83,7 +86,7 @@ fn HomePage() -> impl IntoView {
                     <Badge variant=BadgeVariant::Outline>Outline</Badge>
                 </div>
             }
-            .into_view(),
+            .into_any(),
         ),
     ];
 
@@ -209,7 +212,7 @@ fn Header() -> impl IntoView {
 #[component]
 fn ErrorPage() -> impl IntoView {
     let params = use_params_map();
-    let p_unknown = move || params.with(|p| p.get("else").cloned().unwrap_or_default());
+    let p_unknown = move || params.with(|p| p.get("else").unwrap_or_default());
 
     let unknown = p_unknown();
 
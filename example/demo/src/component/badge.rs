@@ -1,8 +1,9 @@
-use leptos::*;
+use leptos::prelude::*;
 use tailwind_fuse::*;
 
 #[derive(TwClass)]
 #[tw(
+    signals,
     class = "inline-flex items-center rounded-md border px-2.5 py-0.5 text-xs font-semibold transition-colors focus:outline-none focus:ring-2 focus:ring-ring focus:ring-offset-2"
 )]
 pub struct BadgeClass {
@@ -30,22 +31,14 @@ pub enum BadgeVariant {
 
 #[component]
 pub fn Badge(
-    #[prop(into, optional)] variant: MaybeSignal<BadgeVariant>,
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    /// Arbitrary additional attributes.
-    #[prop(attrs)]
-    attributes: Vec<(&'static str, Attribute)>,
+    #[prop(into, optional)] variant: Signal<BadgeVariant>,
+    #[prop(into, optional)] class: Signal<String>,
     children: Children,
 ) -> impl IntoView {
-    let class = create_memo(move |_| {
-        let badge = BadgeClass {
-            variant: variant.get(),
-        };
-        badge.with_class(class.get())
-    });
+    let class = BadgeClassSignals { variant }.with_class(class);
 
     view! {
-        <span {..attributes} class=class>
+        <span class=class>
             {children()}
         </span>
     }
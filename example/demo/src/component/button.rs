@@ -1,23 +1,17 @@
-use leptos::*;
+use leptos::prelude::*;
 use tailwind_fuse::*;
 
 #[component]
 pub fn Button(
-    #[prop(into, optional)] variant: MaybeSignal<ButtonVariant>,
-    #[prop(into, optional)] size: MaybeSignal<ButtonSize>,
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
+    #[prop(into, optional)] variant: Signal<ButtonVariant>,
+    #[prop(into, optional)] size: Signal<ButtonSize>,
+    #[prop(into, optional)] class: Signal<String>,
     children: Children,
 ) -> impl IntoView {
-    let class = create_memo(move |_| {
-        let variant = variant.get();
-        let size = size.get();
-        let button = ButtonClass { variant, size };
-        button.with_class(class.get())
-    });
+    let class = ButtonClassSignals { variant, size }.with_class(class);
 
     view! {
-        <button {..attributes} class=class>
+        <button class=class>
             {children()}
         </button>
     }
@@ -25,6 +19,7 @@ pub fn Button(
 
 #[derive(TwClass)]
 #[tw(
+    signals,
     class = r#"inline-flex items-center justify-center whitespace-nowrap rounded-md text-sm font-medium transition-colors 
                focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-ring disabled:pointer-events-none disabled:opacity-50"#
 )]
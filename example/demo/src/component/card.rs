@@ -1,21 +1,15 @@
-use leptos::*;
+use leptos::prelude::*;
 use tailwind_fuse::*;
 
 #[component]
-pub fn Card(
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
-    children: Children,
-) -> impl IntoView {
-    let class = create_memo(move |_| {
-        tw_merge!(
-            "rounded-xl border bg-card text-card-foreground shadow",
-            class.get()
-        )
-    });
+pub fn Card(#[prop(into, optional)] class: Signal<String>, children: Children) -> impl IntoView {
+    let class = tw_merge_signal!(
+        "rounded-xl border bg-card text-card-foreground shadow",
+        class
+    );
 
     view! {
-        <div {..attributes} class=class>
+        <div class=class>
             {children()}
         </div>
     }
@@ -23,14 +17,13 @@ pub fn Card(
 
 #[component]
 pub fn CardHeader(
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
+    #[prop(into, optional)] class: Signal<String>,
     children: Children,
 ) -> impl IntoView {
-    let class = create_memo(move |_| tw_merge!("flex flex-col space-y-1.5 p-6", class.get()));
+    let class = tw_merge_signal!("flex flex-col space-y-1.5 p-6", class);
 
     view! {
-        <div {..attributes} class=class>
+        <div class=class>
             {children()}
         </div>
     }
@@ -38,15 +31,13 @@ pub fn CardHeader(
 
 #[component]
 pub fn CardTitle(
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
+    #[prop(into, optional)] class: Signal<String>,
     children: Children,
 ) -> impl IntoView {
-    let class =
-        create_memo(move |_| tw_merge!("font-semibold leading-none tracking-tight", class.get()));
+    let class = tw_merge_signal!("font-semibold leading-none tracking-tight", class);
 
     view! {
-        <h3 {..attributes} class=class>
+        <h3 class=class>
             {children()}
         </h3>
     }
@@ -54,14 +45,13 @@ pub fn CardTitle(
 
 #[component]
 pub fn CardDescription(
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
+    #[prop(into, optional)] class: Signal<String>,
     children: Children,
 ) -> impl IntoView {
-    let class = create_memo(move |_| tw_merge!("text-sm text-muted-foreground", class.get()));
+    let class = tw_merge_signal!("text-sm text-muted-foreground", class);
 
     view! {
-        <p {..attributes} class=class>
+        <p class=class>
             {children()}
         </p>
     }
@@ -69,14 +59,13 @@ pub fn CardDescription(
 
 #[component]
 pub fn CardContent(
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
+    #[prop(into, optional)] class: Signal<String>,
     children: Children,
 ) -> impl IntoView {
-    let class = create_memo(move |_| tw_merge!("p-6 pt-0", class.get()));
+    let class = tw_merge_signal!("p-6 pt-0", class);
 
     view! {
-        <div {..attributes} class=class>
+        <div class=class>
             {children()}
         </div>
     }
@@ -84,14 +73,13 @@ pub fn CardContent(
 
 #[component]
 pub fn CardFooter(
-    #[prop(into, optional)] class: MaybeSignal<String>,
-    #[prop(attrs)] attributes: Vec<(&'static str, Attribute)>,
+    #[prop(into, optional)] class: Signal<String>,
     children: Children,
 ) -> impl IntoView {
-    let class = create_memo(move |_| tw_merge!("flex items-center p-6 pt-0", class.get()));
+    let class = tw_merge_signal!("flex items-center p-6 pt-0", class);
 
     view! {
-        <div {..attributes} class=class>
+        <div class=class>
             {children()}
         </div>
     }
@@ -1,15 +1,13 @@
 use app::*;
-use leptos::*;
 mod app;
 mod component;
+mod theme;
 
 pub fn main() {
     _ = console_log::init_with_level(log::Level::Debug);
     console_error_panic_hook::set_once();
 
-    logging::log!("csr mode - mounting to body");
+    leptos::logging::log!("csr mode - mounting to body");
 
-    mount_to_body(|| {
-        view! { <App/> }
-    });
+    leptos::mount::mount_to_body(App);
 }
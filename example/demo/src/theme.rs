@@ -0,0 +1,27 @@
+use leptos::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+/// Provides the theme to `use_theme`, and toggles the `dark` class on `<html>` for Tailwind.
+#[component]
+pub fn ThemeProvider(children: Children) -> impl IntoView {
+    let theme = RwSignal::new(Theme::Light);
+    provide_context(theme);
+
+    Effect::new(move |_| {
+        let dark = theme.get() == Theme::Dark;
+        if let Some(html) = document().document_element() {
+            _ = html.class_list().toggle_with_force("dark", dark);
+        }
+    });
+
+    children()
+}
+
+pub fn use_theme() -> RwSignal<Theme> {
+    expect_context()
+}
@@ -0,0 +1,174 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::merge::{MergeConfig, TailwindConfigError, THEME_UTILITIES};
+
+/// Generate Rust source from a Tailwind config export, and write it to `out_file`.
+///
+/// Call this from your `build.rs`, then `include!` the generated file.
+/// See [`generate`] for what the file contains.
+///
+/// ## Example
+///
+/// ```no_run
+/// // build.rs
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// tailwind_fuse::build::compile(
+///     "tailwind.config.json",
+///     std::path::Path::new(&out_dir).join("tailwind_merge.rs"),
+/// )
+/// .unwrap();
+/// ```
+///
+/// ```ignore
+/// // main.rs
+/// use tailwind_fuse::merge::*;
+///
+/// include!(concat!(env!("OUT_DIR"), "/tailwind_merge.rs"));
+///
+/// pub fn my_tw_merge(class: &str) -> String {
+///     tw_merge_override(&[class], TAILWIND_MERGE_OPTIONS, TailwindCollisionId, TailwindCollisions)
+/// }
+/// ```
+pub fn compile(
+    config: impl AsRef<Path>,
+    out_file: impl AsRef<Path>,
+) -> Result<(), TailwindConfigError> {
+    let config = config.as_ref();
+    println!("cargo:rerun-if-changed={}", config.display());
+
+    let source = generate(&MergeConfig::from_tailwind_config(config)?);
+    std::fs::write(out_file, source).map_err(TailwindConfigError::Io)
+}
+
+/// Generate Rust source for the `prefix`, `separator`, `version` and theme of a [`MergeConfig`].
+///
+/// The source contains:
+/// - `TAILWIND_MERGE_OPTIONS`, a [`crate::merge::MergeOptions`] with the prefix, separator and version
+/// - `TailwindCollisionId`, a [`crate::merge::CollisionIdFn`] matching the classes that use a theme key,
+///   following the same [`THEME_UTILITIES`] as [`crate::merge::tw_merge_config`]
+/// - `TailwindCollisions`, a [`crate::merge::GetCollisionsFn`] with the default collisions, extended by
+///   [`MergeConfig::collisions`]
+///
+/// Rules added with [`MergeConfig::collision_id_fn`] and [`MergeConfig::collisions_fn`] are code, so they're not included.
+pub fn generate(config: &MergeConfig) -> String {
+    let theme = config.get_theme();
+
+    let mut arms = Arms::default();
+    for &(utility, section, collision_id) in THEME_UTILITIES {
+        for key in theme.get_keys(section) {
+            arms.push(utility, key, collision_id);
+        }
+    }
+
+    let mut extra_collisions = config.get_extra_collisions().iter().collect::<Vec<_>>();
+    extra_collisions.sort();
+    let mut collisions = String::new();
+    for (collision_id, extra) in extra_collisions {
+        let _ = write!(
+            collisions,
+            r#"            {collision_id:?} => {{
+                let mut collisions = tailwind_fuse::merge::get_collisions(collision_id).unwrap_or_default();
+                collisions.extend({extra:?});
+                Some(collisions)
+            }}
+"#
+        );
+    }
+
+    let mut source = String::new();
+    let _ = write!(
+        source,
+        r#"// @generated by tailwind_fuse::build. Do not edit.
+
+/// Prefix, separator and version from the Tailwind config.
+pub const TAILWIND_MERGE_OPTIONS: tailwind_fuse::merge::MergeOptions = tailwind_fuse::merge::MergeOptions {{
+    prefix: {prefix:?},
+    separator: {separator:?},
+    version: tailwind_fuse::merge::TailwindVersion::{version:?},
+}};
+
+/// Collision ids for the classes that use a key from the Tailwind config theme.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TailwindCollisionId;
+
+impl tailwind_fuse::merge::CollisionIdFn for TailwindCollisionId {{
+    #[allow(clippy::match_single_binding)] // when the config has no rules
+    fn apply(&self, elements: &[&str], _arbitrary: Option<&str>) -> Option<&'static str> {{
+        match elements {{
+{arms}            _ => None,
+        }}
+    }}
+}}
+
+/// Collisions from the Tailwind config, which extend the default collisions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TailwindCollisions;
+
+impl tailwind_fuse::merge::GetCollisionsFn for TailwindCollisions {{
+    #[allow(clippy::match_single_binding)] // when the config has no rules
+    fn apply(&self, collision_id: &str) -> Option<Vec<&'static str>> {{
+        match collision_id {{
+{collisions}            _ => None,
+        }}
+    }}
+}}
+"#,
+        prefix = config.get_prefix(),
+        separator = config.get_separator(),
+        version = config.get_version(),
+        arms = arms.source,
+        collisions = collisions,
+    );
+    source
+}
+
+#[derive(Default)]
+struct Arms {
+    seen: HashSet<String>,
+    source: String,
+}
+
+impl Arms {
+    // `("inset-x", "primary-foreground")` becomes `["inset", "x", "primary", "foreground"]`
+    fn push(&mut self, utility: &str, key: &str, collision_id: &str) {
+        let pattern = utility
+            .split('-')
+            .chain(key.split('-'))
+            .map(|element| format!("{element:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if self.seen.insert(pattern.clone()) {
+            let _ = writeln!(
+                self.source,
+                "            [{pattern}] => Some({collision_id:?}),"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_generate() {
+    let config = MergeConfig::new()
+        .prefix("tw-")
+        .version(crate::merge::TailwindVersion::V4)
+        .collisions("font-size", ["text-color"])
+        .theme(
+            crate::merge::MergeTheme::new()
+                .font_size(["body"])
+                .colors(["body", "primary-foreground"])
+                .spacing(["gutter"]),
+        );
+    let source = generate(&config);
+
+    assert!(source.contains(r#"prefix: "tw-","#));
+    assert!(source.contains("version: tailwind_fuse::merge::TailwindVersion::V4,"));
+    assert!(source.contains("pub struct TailwindCollisions;"));
+    assert!(source.contains(r#"collisions.extend(["text-color"]);"#));
+    assert!(source.contains(r#"["text", "body"] => Some("font-size"),"#));
+    assert!(!source.contains(r#"["text", "body"] => Some("text-color"),"#));
+    assert!(source.contains(r#"["bg", "body"] => Some("background-color"),"#));
+    assert!(source.contains(r#"["ring", "primary", "foreground"] => Some("ring-color"),"#));
+    assert!(source.contains(r#"["inset", "x", "gutter"] => Some("inset-x"),"#));
+}
@@ -1,7 +1,15 @@
-use std::sync::OnceLock;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+pub use tailwind_fuse_core::TailwindVersion;
+
+use super::{CollisionIdFn, GetCollisionsFn, MergeTheme};
 
 /// Configuration for merging Tailwind classes.
 /// If you want to set global options use [`set_merge_options`].
+///
+/// If you need to own the configuration, or need custom collision rules, use [`MergeConfig`].
 #[derive(Clone, Copy, Debug)]
 pub struct MergeOptions {
     /// Custom prefix for modifiers in Tailwind classes
@@ -16,20 +24,31 @@ pub struct MergeOptions {
     ///
     /// <https://tailwindcss.com/docs/configuration#separator>
     pub separator: &'static str,
+    /// Tailwind version used to parse and classify classes
+    ///
+    /// Default is [`TailwindVersion::V3`]
+    pub version: TailwindVersion,
 }
 
 impl Default for MergeOptions {
+    /// The prefix, separator and version of the global [`MergeConfig`], see [`set_merge_config`].
+    ///
+    /// Only these are kept. The theme and custom collision rules of the global config are not,
+    /// so a `MergeConfig` converted back from these options doesn't have them.
     fn default() -> Self {
-        MERGE_OVERRIDE
-            .get()
-            .copied()
-            .unwrap_or(DEFAULT_MERGE_OPTIONS)
+        let config = get_merge_config();
+        MergeOptions {
+            prefix: config.get_prefix(),
+            separator: config.get_separator(),
+            version: config.get_version(),
+        }
     }
 }
 
 const DEFAULT_MERGE_OPTIONS: MergeOptions = MergeOptions {
     prefix: "",
     separator: ":",
+    version: TailwindVersion::V3,
 };
 
 impl From<MergeOptions> for crate::ast::AstParseOptions<'static> {
@@ -37,14 +56,246 @@ impl From<MergeOptions> for crate::ast::AstParseOptions<'static> {
         crate::ast::AstParseOptions {
             prefix: options.prefix,
             separator: options.separator,
+            version: options.version,
         }
     }
 }
 
-pub(crate) static MERGE_OVERRIDE: OnceLock<MergeOptions> = OnceLock::new();
+/// Owned configuration for merging Tailwind classes.
+///
+/// Unlike [`MergeOptions`], a `MergeConfig` can be built at runtime, and carries custom collision rules.
+/// Use it with [`crate::merge::tw_merge_config`] or [`crate::merge::tw_merge_slice_config`].
+///
+/// ## Example
+///
+/// ```
+/// # use tailwind_fuse::merge::*;
+/// let config = MergeConfig::new()
+///     .prefix("tw-")
+///     .collision_id_fn(|elements: &[&str], _: Option<&str>| match elements {
+///         ["brand", _] => Some("brand"),
+///         _ => None,
+///     })
+///     .collisions("brand", ["text-color"]);
+///
+/// assert_eq!("tw-brand-lg", tw_merge_config("tw-brand-sm tw-brand-lg", &config));
+/// assert_eq!("tw-brand-lg", tw_merge_config("tw-text-red-500 tw-brand-lg", &config));
+/// assert_eq!("tw-brand-lg tw-text-red-500", tw_merge_config("tw-brand-lg tw-text-red-500", &config));
+/// ```
+#[derive(Clone)]
+pub struct MergeConfig {
+    prefix: String,
+    separator: String,
+    version: TailwindVersion,
+    theme: MergeTheme,
+    collision_id_fns: Vec<Arc<dyn CollisionIdFn + Send + Sync>>,
+    collisions_fns: Vec<Arc<dyn GetCollisionsFn + Send + Sync>>,
+    collisions: HashMap<String, Vec<String>>,
+}
+
+impl MergeConfig {
+    /// Create a config with no prefix, `:` separator and no custom collision rules.
+    pub fn new() -> Self {
+        DEFAULT_MERGE_OPTIONS.into()
+    }
+
+    /// Set the custom prefix for modifiers in Tailwind classes
+    ///
+    /// <https://tailwindcss.com/docs/configuration#prefix>
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Set the custom separator for modifiers in Tailwind classes
+    ///
+    /// <https://tailwindcss.com/docs/configuration#separator>
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Set the Tailwind version used to parse and classify classes
+    pub fn version(mut self, version: TailwindVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Set the custom theme keys, used to classify classes that use them
+    pub fn theme(mut self, theme: MergeTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Add a custom collision id rule.
+    ///
+    /// Rules are tried in the order they were added, before the default rules.
+    pub fn collision_id_fn(
+        mut self,
+        collision_id_fn: impl CollisionIdFn + Send + Sync + 'static,
+    ) -> Self {
+        self.collision_id_fns.push(Arc::new(collision_id_fn));
+        self
+    }
+
+    /// Add a custom collisions rule.
+    ///
+    /// Rules are tried in the order they were added. The first rule to return a list replaces the default collisions.
+    pub fn collisions_fn(
+        mut self,
+        collisions_fn: impl GetCollisionsFn + Send + Sync + 'static,
+    ) -> Self {
+        self.collisions_fns.push(Arc::new(collisions_fn));
+        self
+    }
+
+    /// Add CollisionIds that collide with the given CollisionId.
+    ///
+    /// These extend the default collisions, instead of replacing them.
+    pub fn collisions<I, S>(mut self, collision_id: impl Into<String>, collisions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.collisions
+            .entry(collision_id.into())
+            .or_default()
+            .extend(collisions.into_iter().map(Into::into));
+        self
+    }
+
+    /// Custom prefix for modifiers in Tailwind classes
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Custom separator for modifiers in Tailwind classes
+    pub fn get_separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// Tailwind version used to parse and classify classes
+    pub fn get_version(&self) -> TailwindVersion {
+        self.version
+    }
+
+    /// Custom theme keys
+    pub fn get_theme(&self) -> &MergeTheme {
+        &self.theme
+    }
+
+    /// CollisionIds added with [`MergeConfig::collisions`], by the CollisionId they collide with.
+    pub fn get_extra_collisions(&self) -> &HashMap<String, Vec<String>> {
+        &self.collisions
+    }
+
+    pub(crate) fn parse_options(&self) -> crate::ast::AstParseOptions<'_> {
+        crate::ast::AstParseOptions {
+            prefix: &self.prefix,
+            separator: &self.separator,
+            version: self.version,
+        }
+    }
+
+    pub(crate) fn get_collision_id(
+        &self,
+        elements: &[&str],
+        arbitrary: Option<&str>,
+        postfix: Option<&str>,
+    ) -> Option<&'static str> {
+        self.collision_id_fns
+            .iter()
+            .find_map(|f| f.apply_with_postfix(elements, arbitrary, postfix))
+    }
+
+    pub(crate) fn get_collisions<'a>(&'a self, collision_id: &str) -> Option<Vec<&'a str>> {
+        if let Some(collisions) = self
+            .collisions_fns
+            .iter()
+            .find_map(|f| f.apply(collision_id))
+        {
+            return Some(collisions);
+        }
+
+        let defaults = tailwind_fuse_core::get_collisions::get_collisions(collision_id);
+        match self.collisions.get(collision_id) {
+            Some(extra) => {
+                let mut collisions: Vec<&str> = defaults.unwrap_or_default();
+                collisions.extend(extra.iter().map(String::as_str));
+                Some(collisions)
+            }
+            None => defaults,
+        }
+    }
+}
+
+impl Default for MergeConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<MergeOptions> for MergeConfig {
+    fn from(options: MergeOptions) -> Self {
+        Self {
+            prefix: options.prefix.to_string(),
+            separator: options.separator.to_string(),
+            version: options.version,
+            theme: MergeTheme::default(),
+            collision_id_fns: vec![],
+            collisions_fns: vec![],
+            collisions: HashMap::new(),
+        }
+    }
+}
+
+impl fmt::Debug for MergeConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeConfig")
+            .field("prefix", &self.prefix)
+            .field("separator", &self.separator)
+            .field("version", &self.version)
+            .field("theme", &self.theme)
+            .field("collision_id_fns", &self.collision_id_fns.len())
+            .field("collisions_fns", &self.collisions_fns.len())
+            .field("collisions", &self.collisions)
+            .finish()
+    }
+}
+
+pub(crate) static MERGE_CONFIG: OnceLock<MergeConfig> = OnceLock::new();
 
 /// Set global options for merging Tailwind classes.
 /// Useful for getting all the macros to work with custom options.
-pub fn set_merge_options(options: MergeOptions) {
-    let _ = MERGE_OVERRIDE.set(options);
+///
+/// Shorthand for [`set_merge_config`] without custom collision rules.
+pub fn set_merge_options(options: MergeOptions) -> Result<(), MergeConfigAlreadySet> {
+    set_merge_config(options.into())
+}
+
+/// Set the global [`MergeConfig`] used by [`crate::tw_merge!`] and the variant macros.
+///
+/// This can only be set once. Subsequent calls are ignored and return [`MergeConfigAlreadySet`].
+pub fn set_merge_config(config: MergeConfig) -> Result<(), MergeConfigAlreadySet> {
+    MERGE_CONFIG.set(config).map_err(|_| MergeConfigAlreadySet)
+}
+
+/// Returned by [`set_merge_config`] and [`set_merge_options`] when the global config was already set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeConfigAlreadySet;
+
+impl fmt::Display for MergeConfigAlreadySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the global merge config was already set")
+    }
+}
+
+impl std::error::Error for MergeConfigAlreadySet {}
+
+/// Get the global [`MergeConfig`], or the default config if none has been set.
+pub fn get_merge_config() -> &'static MergeConfig {
+    static DEFAULT: OnceLock<MergeConfig> = OnceLock::new();
+    MERGE_CONFIG
+        .get()
+        .unwrap_or_else(|| DEFAULT.get_or_init(MergeConfig::new))
 }
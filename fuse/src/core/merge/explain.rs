@@ -0,0 +1,58 @@
+pub use crate::ast::AstStyle;
+pub use tailwind_fuse_core::merge::ClassExplanation;
+
+use super::{get_merge_config, MergeConfig};
+
+/// Report of how [`tw_merge_explain`] resolved each class.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergeExplanation<'a> {
+    /// The merged classes, same as [`crate::merge::tw_merge`]
+    pub result: String,
+    /// One entry per class, in input order
+    pub classes: Vec<ClassExplanation<'a>>,
+}
+
+impl<'a> MergeExplanation<'a> {
+    fn new(classes: Vec<ClassExplanation<'a>>) -> Self {
+        let result = classes
+            .iter()
+            .filter(|class| class.is_kept())
+            .map(|class| class.class)
+            .collect::<Vec<_>>()
+            .join(" ");
+        Self { result, classes }
+    }
+
+    /// The class that removed the class at `index`, if any.
+    pub fn removed_by(&self, index: usize) -> Option<&ClassExplanation<'a>> {
+        let removed_by = self.classes.get(index)?.removed_by?;
+        self.classes.get(removed_by)
+    }
+}
+
+/// Merges all the Tailwind classes like [`crate::merge::tw_merge`], and reports why each class was kept or removed.
+///
+/// Uses the global [`MergeConfig`], see [`crate::merge::set_merge_config`].
+///
+/// ## Example
+///
+/// ```
+/// # use tailwind_fuse::merge::*;
+/// let explanation = tw_merge_explain("px-2 p-4 hover:p-2");
+///
+/// assert_eq!(explanation.result, "p-4 hover:p-2");
+/// assert_eq!(explanation.classes[0].collision_id, Ok("padding-x"));
+/// assert_eq!(explanation.removed_by(0).unwrap().class, "p-4");
+/// assert!(explanation.classes[2].is_kept());
+/// ```
+pub fn tw_merge_explain(class: &str) -> MergeExplanation<'_> {
+    tw_merge_explain_config(class, get_merge_config())
+}
+
+/// Merges all the Tailwind classes like [`crate::merge::tw_merge_config`], and reports why each class was kept or removed.
+pub fn tw_merge_explain_config<'a>(
+    class: &'a str,
+    config: &'a MergeConfig,
+) -> MergeExplanation<'a> {
+    MergeExplanation::new(super::merge_impl::tw_merge_explain_config(&[class], config))
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+use std::ops::Range;
+
+use super::get_collision_id::UNKNOWN_CLASS;
+use super::{get_merge_config, tw_merge_explain_config, AstStyle, MergeConfig};
+use tailwind_fuse_core::merge::collision_id_table;
+
+/// A problem with a single class, found by [`tw_lint`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic<'a> {
+    /// The class as written
+    pub class: &'a str,
+    /// Byte range of the class in the linted string
+    pub span: Range<usize>,
+    /// What kind of problem was found
+    pub kind: DiagnosticKind,
+    /// Why the class is invalid (e.g. `Invalid z index`)
+    pub message: &'static str,
+    /// A replacement class, when one is obvious (e.g. `flex` for `flx`)
+    pub suggestion: Option<String>,
+}
+
+/// The kind of problem found by [`tw_lint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DiagnosticKind {
+    /// The class is not valid Tailwind syntax (e.g. unbalanced brackets)
+    Unparseable,
+    /// The class doesn't match any known utility (e.g. `flx`)
+    UnknownClass,
+    /// The utility is known, but the value is invalid (e.g. `z-abc`)
+    InvalidValue,
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}..{}: {}: `{}`",
+            self.span.start, self.span.end, self.message, self.class
+        )?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, ", did you mean `{suggestion}`?")?;
+        }
+        Ok(())
+    }
+}
+
+/// Finds classes that can't be parsed, don't match a known utility, or have an invalid value.
+/// Marker classes that don't match a utility, like `group`, `peer/label` and `prose`, are valid.
+///
+/// Uses the global [`MergeConfig`], see [`crate::merge::set_merge_config`].
+///
+/// ## Example
+///
+/// ```
+/// # use tailwind_fuse::merge::*;
+/// let diagnostics = tw_lint("flex z-abc flx");
+///
+/// assert_eq!(diagnostics[0].class, "z-abc");
+/// assert_eq!(diagnostics[0].span, 5..10);
+/// assert_eq!(diagnostics[0].kind, DiagnosticKind::InvalidValue);
+///
+/// assert_eq!(diagnostics[1].kind, DiagnosticKind::UnknownClass);
+/// assert_eq!(diagnostics[1].suggestion.as_deref(), Some("flex"));
+/// ```
+pub fn tw_lint(class: &str) -> Vec<Diagnostic<'_>> {
+    tw_lint_config(class, get_merge_config())
+}
+
+/// Like [`tw_lint`], with the provided [`MergeConfig`].
+pub fn tw_lint_config<'a>(class: &'a str, config: &'a MergeConfig) -> Vec<Diagnostic<'a>> {
+    tw_merge_explain_config(class, config)
+        .classes
+        .into_iter()
+        .filter_map(|explanation| {
+            let message = explanation.collision_id.err()?;
+            if explanation.style.as_ref().is_some_and(is_marker) {
+                return None;
+            }
+            let start = explanation.class.as_ptr() as usize - class.as_ptr() as usize;
+            let (kind, suggestion) = match &explanation.style {
+                None => (DiagnosticKind::Unparseable, None),
+                Some(style) if message == UNKNOWN_CLASS => {
+                    (DiagnosticKind::UnknownClass, suggest(style, config))
+                }
+                Some(_) => (DiagnosticKind::InvalidValue, None),
+            };
+            Some(Diagnostic {
+                class: explanation.class,
+                span: start..start + explanation.class.len(),
+                kind,
+                message,
+                suggestion,
+            })
+        })
+        .collect()
+}
+
+// Classes that don't style anything, but mark an element for other classes (e.g. `group` for `group-hover:`).
+fn is_marker(style: &AstStyle) -> bool {
+    if style.arbitrary.is_some() || style.negative {
+        return false;
+    }
+    match style.elements.as_slice() {
+        // `group/item` names the group for `group-hover/item:`.
+        ["group"] | ["peer"] => true,
+        // From the typography plugin.
+        ["prose"] | ["not", "prose"] => style.postfix.is_none(),
+        _ => false,
+    }
+}
+
+// Looks for a known utility one edit away from one of the elements (e.g. `flx` -> `flex`).
+fn suggest(style: &AstStyle, config: &MergeConfig) -> Option<String> {
+    let get_collision_id = collision_id_table(config.get_version());
+    let is_known = |elements: &[&str]| {
+        config
+            .get_collision_id(elements, style.arbitrary, style.postfix)
+            .is_some()
+            || get_collision_id(
+                elements,
+                style.arbitrary.unwrap_or_default(),
+                config.get_theme(),
+            )
+            .is_ok()
+    };
+
+    style
+        .elements
+        .iter()
+        .enumerate()
+        .find_map(|(index, element)| {
+            edits(element).into_iter().find_map(|candidate| {
+                let mut elements = style.elements.clone();
+                elements[index] = &candidate;
+                if !is_known(&elements) {
+                    return None;
+                }
+                let start = element.as_ptr() as usize - style.source.as_ptr() as usize;
+                let end = start + element.len();
+                Some(format!(
+                    "{}{candidate}{}",
+                    &style.source[..start],
+                    &style.source[end..]
+                ))
+            })
+        })
+}
+
+// Every string one deletion, transposition, substitution or insertion away, in that order.
+fn edits(element: &str) -> Vec<String> {
+    const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+    let chars: Vec<char> = element.chars().collect();
+    let mut edits = vec![];
+
+    for i in 0..chars.len() {
+        let mut edit = chars.clone();
+        edit.remove(i);
+        edits.push(edit);
+    }
+    for i in 1..chars.len() {
+        let mut edit = chars.clone();
+        edit.swap(i - 1, i);
+        edits.push(edit);
+    }
+    for i in 0..chars.len() {
+        for letter in LETTERS.chars() {
+            let mut edit = chars.clone();
+            edit[i] = letter;
+            edits.push(edit);
+        }
+    }
+    for i in 0..=chars.len() {
+        for letter in LETTERS.chars() {
+            let mut edit = chars.clone();
+            edit.insert(i, letter);
+            edits.push(edit);
+        }
+    }
+
+    edits
+        .into_iter()
+        .map(|edit| edit.into_iter().collect::<String>())
+        .filter(|edit| !edit.is_empty() && edit != element)
+        .collect()
+}
+
+#[test]
+fn test_edits() {
+    let edits = edits("ab");
+    assert!(edits.contains(&"a".to_string()));
+    assert!(edits.contains(&"ba".to_string()));
+    assert!(edits.contains(&"ac".to_string()));
+    assert!(edits.contains(&"abc".to_string()));
+    assert!(!edits.contains(&"ab".to_string()));
+}
@@ -1,11 +1,21 @@
 pub(crate) mod config;
-pub(crate) mod get_collision_id;
-pub(crate) mod get_collisions;
+pub(crate) mod explain;
+pub(crate) mod lint;
 pub(crate) mod merge_impl;
-pub(crate) mod validators;
+#[cfg(feature = "config")]
+pub(crate) mod tailwind_config;
 
 pub use config::*;
-pub use merge_impl::tw_merge_override;
+pub use explain::*;
+pub use lint::*;
+pub use merge_impl::{tw_merge_override, tw_merge_override_theme};
+#[cfg(feature = "config")]
+pub use tailwind_config::TailwindConfigError;
+/// The default CollisionIds that collide with a CollisionId.
+pub use tailwind_fuse_core::get_collisions::get_collisions;
+pub use tailwind_fuse_core::{MergeTheme, ThemeSection, THEME_UTILITIES};
+
+pub(crate) use tailwind_fuse_core::get_collision_id;
 
 /// Merges all the Tailwind classes, resolving conflicts.
 ///
@@ -13,7 +23,7 @@ pub use merge_impl::tw_merge_override;
 ///
 /// If you DON'T want to handle conflicts use [`crate::tw_join!`].
 ///
-/// If you want to set global options use [`crate::merge::set_merge_options`].
+/// If you want to set global options use [`crate::merge::set_merge_options`] or [`crate::merge::set_merge_config`].
 ///
 /// If you want a custom type to be used with this macro, implement the [`crate::MaybeIntoTailwindClass`] trait.
 #[macro_export]
@@ -26,19 +36,23 @@ macro_rules! tw_merge {
 
 /// Merges all the Tailwind classes in the string, resolving conflicts.
 ///
-/// If you need custom options use [`tw_merge_options`].
+/// Uses the global [`MergeConfig`], see [`set_merge_config`].
+///
+/// If you need custom options use [`tw_merge_options`] or [`tw_merge_config`].
 #[inline]
 pub fn tw_merge(class: impl AsRef<str>) -> String {
-    tw_merge_slice_options(&[class.as_ref()], Default::default())
+    tw_merge_slice_config(&[class.as_ref()], get_merge_config())
 }
 
 /// Merges all the Tailwind classes in the provided strings, resolving conflicts.
 /// Useful to avoid collecting all the strings into a single string.
 ///
-/// If you need custom options use [`tw_merge_slice_options`].
+/// Uses the global [`MergeConfig`], see [`set_merge_config`].
+///
+/// If you need custom options use [`tw_merge_slice_options`] or [`tw_merge_slice_config`].
 #[inline]
 pub fn tw_merge_slice(class: &[&str]) -> String {
-    tw_merge_slice_options(class, Default::default())
+    tw_merge_slice_config(class, get_merge_config())
 }
 
 /// Merges all the Tailwind classes, resolving conflicts, with the provided options.
@@ -50,6 +64,7 @@ pub fn tw_merge_slice(class: &[&str]) -> String {
 /// const OPTIONS: MergeOptions = MergeOptions {
 ///   prefix: "tw-",
 ///   separator: ":",
+///   version: TailwindVersion::V3,
 /// };
 ///
 /// pub fn my_custom_tw_merge(class: impl AsRef<str>) -> String {
@@ -78,6 +93,7 @@ pub fn tw_merge_options(class: impl AsRef<str>, options: MergeOptions) -> String
 /// const OPTIONS: MergeOptions = MergeOptions {
 ///   prefix: "tw-",
 ///   separator: ":",
+///   version: TailwindVersion::V3,
 /// };
 ///
 /// pub fn my_custom_tw_merge(class: &[&str]) -> String {
@@ -94,16 +110,56 @@ pub fn tw_merge_slice_options(class: &[&str], options: MergeOptions) -> String {
     )
 }
 
+/// Merges all the Tailwind classes, resolving conflicts, with the provided [`MergeConfig`].
+///
+/// ## Example: Multiple Configurations
+///
+/// ```
+/// # use tailwind_fuse::merge::*;
+/// let admin = MergeConfig::new().prefix("admin-");
+/// let shop = MergeConfig::new().prefix("shop-").separator("_");
+///
+/// assert_eq!("hover:admin-p-4", tw_merge_config("hover:admin-p-2 hover:admin-p-4", &admin));
+/// assert_eq!("hover_shop-p-4", tw_merge_config("hover_shop-p-2 hover_shop-p-4", &shop));
+/// ```
+#[inline]
+pub fn tw_merge_config(class: impl AsRef<str>, config: &MergeConfig) -> String {
+    merge_impl::tw_merge_config(&[class.as_ref()], config)
+}
+
+/// Merges all the Tailwind classes in the provided strings, resolving conflicts, with the provided [`MergeConfig`].
+/// Useful to avoid collecting all the strings into a single string.
+#[inline]
+pub fn tw_merge_slice_config(class: &[&str], config: &MergeConfig) -> String {
+    merge_impl::tw_merge_config(class, config)
+}
+
 /// Return a ConflictId for a given Tailwind Class.
 pub trait CollisionIdFn {
     /// elements: parts of the Tailwind class separated by `-`.
     ///
     /// (e.g. `bg-red-500` would be `["bg", "red", "500"]`)
     ///
+    /// The postfix modifier is not included (e.g. `bg-red-500/50` would also be `["bg", "red", "500"]`),
+    /// see [`CollisionIdFn::apply_with_postfix`].
+    ///
     /// arbitrary: the arbitrary value at the end of the Tailwind class
     ///
     /// <https://tailwindcss.com/docs/adding-custom-styles#using-arbitrary-values>
     fn apply(&self, elements: &[&str], arbitrary: Option<&str>) -> Option<&'static str>;
+
+    /// Like [`CollisionIdFn::apply`], with the postfix modifier (e.g. `50` in `bg-brand/50`).
+    ///
+    /// Defaults to [`CollisionIdFn::apply`], ignoring the postfix.
+    fn apply_with_postfix(
+        &self,
+        elements: &[&str],
+        arbitrary: Option<&str>,
+        postfix: Option<&str>,
+    ) -> Option<&'static str> {
+        let _ = postfix;
+        self.apply(elements, arbitrary)
+    }
 }
 
 impl<F> CollisionIdFn for F
@@ -0,0 +1,225 @@
+use std::fmt;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::{MergeConfig, MergeTheme};
+
+/// Error returned when a Tailwind config export can't be loaded.
+#[derive(Debug)]
+pub enum TailwindConfigError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not valid JSON or TOML.
+    Parse(String),
+    /// A known key has an unexpected type (e.g. `prefix` is not a string).
+    Invalid(String),
+}
+
+impl fmt::Display for TailwindConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read tailwind config: {err}"),
+            Self::Parse(err) => write!(f, "failed to parse tailwind config: {err}"),
+            Self::Invalid(err) => write!(f, "invalid tailwind config: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for TailwindConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl MergeConfig {
+    /// Load the `prefix`, `separator` and theme keys from a JSON export of your Tailwind config.
+    ///
+    /// Both `theme` and `theme.extend` are read. Nested colors are flattened
+    /// (e.g. `primary.foreground` becomes `primary-foreground`), and `DEFAULT` keys map to their parent.
+    ///
+    /// <https://tailwindcss.com/docs/configuration>
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use tailwind_fuse::merge::*;
+    /// let config = MergeConfig::from_tailwind_json(r##"{
+    ///     "theme": {
+    ///         "extend": {
+    ///             "colors": { "primary": { "DEFAULT": "#000", "foreground": "#fff" } },
+    ///             "fontSize": { "body": "1rem" }
+    ///         }
+    ///     }
+    /// }"##).unwrap();
+    ///
+    /// assert_eq!("text-body text-primary-foreground", tw_merge_config("text-body text-primary-foreground", &config));
+    /// assert_eq!("text-primary", tw_merge_config("text-primary-foreground text-primary", &config));
+    /// ```
+    pub fn from_tailwind_json(json: &str) -> Result<Self, TailwindConfigError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| TailwindConfigError::Parse(e.to_string()))?;
+        from_value(&value)
+    }
+
+    /// Load the `prefix`, `separator` and theme keys from a TOML export of your Tailwind config.
+    ///
+    /// See [`MergeConfig::from_tailwind_json`].
+    pub fn from_tailwind_toml(toml: &str) -> Result<Self, TailwindConfigError> {
+        let value: Value =
+            toml::from_str(toml).map_err(|e| TailwindConfigError::Parse(e.to_string()))?;
+        from_value(&value)
+    }
+
+    /// Load a JSON or TOML export of your Tailwind config, picked by the file extension.
+    ///
+    /// Files without a `.toml` extension are read as JSON.
+    pub fn from_tailwind_config(path: impl AsRef<Path>) -> Result<Self, TailwindConfigError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(TailwindConfigError::Io)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_tailwind_toml(&content),
+            _ => Self::from_tailwind_json(&content),
+        }
+    }
+}
+
+fn from_value(value: &Value) -> Result<MergeConfig, TailwindConfigError> {
+    let root = value
+        .as_object()
+        .ok_or_else(|| invalid("expected an object"))?;
+
+    let mut config = MergeConfig::new();
+
+    match root.get("prefix") {
+        Some(Value::String(prefix)) => config = config.prefix(prefix.as_str()),
+        Some(_) => return Err(invalid("`prefix` must be a string")),
+        None => {}
+    }
+
+    match root.get("separator") {
+        Some(Value::String(separator)) => config = config.separator(separator.as_str()),
+        Some(_) => return Err(invalid("`separator` must be a string")),
+        None => {}
+    }
+
+    let theme = match root.get("theme") {
+        Some(Value::Object(theme)) => theme,
+        Some(_) => return Err(invalid("`theme` must be an object")),
+        None => return Ok(config),
+    };
+
+    let mut keys = ThemeKeys::default();
+    add_theme_keys(theme, &mut keys)?;
+    match theme.get("extend") {
+        Some(Value::Object(extend)) => add_theme_keys(extend, &mut keys)?,
+        Some(_) => return Err(invalid("`theme.extend` must be an object")),
+        None => {}
+    }
+
+    Ok(config.theme(keys.into()))
+}
+
+fn add_theme_keys(
+    theme: &Map<String, Value>,
+    keys: &mut ThemeKeys,
+) -> Result<(), TailwindConfigError> {
+    match theme.get("colors") {
+        Some(Value::Object(colors)) => flatten_colors(colors, "", &mut keys.colors),
+        Some(_) => return Err(invalid("`colors` must be an object")),
+        None => {}
+    }
+
+    add_object_keys(theme, "spacing", &mut keys.spacing)?;
+    add_object_keys(theme, "fontSize", &mut keys.font_size)?;
+    add_object_keys(theme, "fontFamily", &mut keys.font_family)?;
+    add_object_keys(theme, "boxShadow", &mut keys.box_shadow)?;
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct ThemeKeys {
+    colors: Vec<String>,
+    spacing: Vec<String>,
+    font_size: Vec<String>,
+    font_family: Vec<String>,
+    box_shadow: Vec<String>,
+}
+
+fn invalid(message: &str) -> TailwindConfigError {
+    TailwindConfigError::Invalid(message.to_string())
+}
+
+// `DEFAULT` is used without a key (e.g. `shadow`), which the default rules already handle.
+fn add_object_keys(
+    theme: &Map<String, Value>,
+    key: &str,
+    keys: &mut Vec<String>,
+) -> Result<(), TailwindConfigError> {
+    match theme.get(key) {
+        Some(Value::Object(values)) => {
+            keys.extend(values.keys().filter(|key| *key != "DEFAULT").cloned());
+            Ok(())
+        }
+        Some(_) => Err(invalid(&format!("`{key}` must be an object"))),
+        None => Ok(()),
+    }
+}
+
+// `{ primary: { DEFAULT, foreground } }` becomes `["primary", "primary-foreground"]`
+fn flatten_colors(colors: &Map<String, Value>, parent: &str, keys: &mut Vec<String>) {
+    for (key, value) in colors {
+        let name = match (parent, key.as_str()) {
+            (parent, "DEFAULT") => {
+                if !parent.is_empty() {
+                    keys.push(parent.to_string());
+                }
+                continue;
+            }
+            ("", key) => key.to_string(),
+            (parent, key) => format!("{parent}-{key}"),
+        };
+        match value {
+            Value::Object(nested) => flatten_colors(nested, &name, keys),
+            _ => keys.push(name),
+        }
+    }
+}
+
+impl From<ThemeKeys> for MergeTheme {
+    fn from(keys: ThemeKeys) -> Self {
+        MergeTheme::new()
+            .colors(keys.colors)
+            .spacing(keys.spacing)
+            .font_size(keys.font_size)
+            .font_family(keys.font_family)
+            .box_shadow(keys.box_shadow)
+    }
+}
+
+#[test]
+fn test_flatten_colors() {
+    let colors = serde_json::json!({
+        "ring": "#000",
+        "primary": { "DEFAULT": "#000", "foreground": "#fff" },
+        "brand": { "50": "#eee", "dark": { "DEFAULT": "#111", "muted": "#222" } },
+    });
+    let mut keys = vec![];
+    flatten_colors(colors.as_object().unwrap(), "", &mut keys);
+    keys.sort();
+    assert_eq!(
+        keys,
+        [
+            "brand-50",
+            "brand-dark",
+            "brand-dark-muted",
+            "primary",
+            "primary-foreground",
+            "ring"
+        ]
+    );
+}
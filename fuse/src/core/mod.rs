@@ -3,6 +3,10 @@ mod join;
 /// Merges all the Tailwind classes, resolving conflicts.
 pub mod merge;
 
+/// Generates collision rules from your Tailwind config at build time.
+#[cfg(feature = "config")]
+pub mod build;
+
 /// Used to extract a &str from a type
 ///
 /// Implement this trait for your type to use it with the [`tw_join!`] and [`tw_merge!`] macros
@@ -11,6 +15,124 @@ pub trait AsTailwindClass {
     fn as_class(&self) -> &str;
 }
 
+/// Like [`AsTailwindClass`], for types that build their class at runtime.
+///
+/// Implemented for every [`AsTailwindClass`], and by `TwVariant` for enums with data-carrying variants (e.g. `Color::Arbitrary(String)`).
+pub trait AsTailwindClassCow {
+    /// Extract a Tailwind class, borrowed when possible
+    fn as_class_cow(&self) -> std::borrow::Cow<'_, str>;
+}
+
+impl<T> AsTailwindClassCow for T
+where
+    T: AsTailwindClass + ?Sized,
+{
+    fn as_class_cow(&self) -> std::borrow::Cow<'_, str> {
+        std::borrow::Cow::Borrowed(self.as_class())
+    }
+}
+
+/// The class of a named part (slot) of a component, used by `TwClass` slots.
+///
+/// Implemented by `TwVariant`, with `#[tw(slot(name = "...", class = "..."))]` on its variants.
+pub trait AsTailwindSlotClass {
+    /// The slots this type adds a class to, which a `TwClass` must declare
+    const SLOTS: &'static [&'static str] = &[];
+
+    /// Extract the Tailwind class of `slot`, empty when there is none
+    fn as_slot_class(&self, slot: &str) -> &str;
+}
+
+impl<T> AsTailwindSlotClass for Option<T>
+where
+    T: AsTailwindSlotClass,
+{
+    const SLOTS: &'static [&'static str] = T::SLOTS;
+
+    fn as_slot_class(&self, slot: &str) -> &str {
+        match self {
+            Some(t) => t.as_slot_class(slot),
+            None => "",
+        }
+    }
+}
+
+/// Whether every slot in `slots` is in `declared`, checked when compiling a `TwClass` with slots.
+#[doc(hidden)]
+pub const fn slots_declared(slots: &[&str], declared: &[&str]) -> bool {
+    let mut i = 0;
+    while i < slots.len() {
+        let mut j = 0;
+        let mut found = false;
+        while j < declared.len() && !found {
+            found = str_eq(slots[i], declared[j]);
+            j += 1;
+        }
+        if !found {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Error returned when parsing a `TwVariant` from a name that doesn't match any variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVariantError {
+    variant: String,
+    expected: &'static [&'static str],
+}
+
+impl ParseVariantError {
+    /// An error for the unknown `variant`, listing the `expected` names.
+    pub fn new(variant: &str, expected: &'static [&'static str]) -> Self {
+        Self {
+            variant: variant.to_string(),
+            expected,
+        }
+    }
+
+    /// The name that failed to parse.
+    pub fn variant(&self) -> &str {
+        &self.variant
+    }
+
+    /// The names of the variants.
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+}
+
+impl std::fmt::Display for ParseVariantError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown variant `{}`, expected one of ", self.variant)?;
+        for (i, name) in self.expected.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{name}`")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseVariantError {}
+
 impl AsTailwindClass for String {
     fn as_class(&self) -> &str {
         self.as_str()
@@ -0,0 +1,37 @@
+//! Framework types used by the code that [`crate::TwClass`] and [`crate::TwVariant`] generate
+//! with the `dioxus`, `yew` and `serde` features.
+//!
+//! [`tw_merge!`](crate::tw_merge) and [`tw_join!`](crate::tw_join) return a `String`,
+//! which both frameworks already accept as a class.
+
+/// Lets a [`crate::TwClass`] struct or builder be used as a Dioxus attribute value.
+#[cfg(feature = "dioxus")]
+pub mod dioxus {
+    pub use ::dioxus_core::prelude::IntoAttributeValue;
+    pub use ::dioxus_core::AttributeValue;
+}
+
+/// Lets a [`crate::TwClass`] struct or builder be used as a Yew `class` or attribute value.
+#[cfg(feature = "yew")]
+pub mod yew {
+    pub use ::yew::html::Classes;
+    pub use ::yew::virtual_dom::AttrValue;
+}
+
+/// Lets a [`crate::TwVariant`] be serialized by name, and a [`crate::TwClass`] builder be deserialized.
+#[cfg(feature = "serde")]
+pub mod serde {
+    pub use ::serde::*;
+
+    /// Deserializes a [`crate::TwVariant`] with its `FromStr` implementation.
+    pub fn deserialize_variant<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: std::str::FromStr<Err = crate::ParseVariantError>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(|error: crate::ParseVariantError| {
+            de::Error::unknown_variant(error.variant(), error.expected())
+        })
+    }
+}
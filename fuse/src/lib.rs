@@ -73,7 +73,7 @@
 //!
 //! Use [`merge::set_merge_options`] to set global options for [`tw_merge!`] and variant macros.
 //!
-//! This can only be set once. Subsequent calls will be ignored.
+//! This can only be set once. Subsequent calls return `Err` and are ignored.
 //!
 //! ```
 //! use tailwind_fuse::{*, merge::*};
@@ -81,6 +81,7 @@
 //! const OPTIONS: MergeOptions = MergeOptions {
 //!     prefix: "tw-",
 //!     separator: ":",
+//!     version: TailwindVersion::V3,
 //! };
 //!
 //! // Before setting options, the default (no prefix) is used
@@ -89,7 +90,7 @@
 //!   tw_merge!("tw-bg-black", "tw-bg-white"),
 //! );
 //!
-//! set_merge_options(OPTIONS);
+//! set_merge_options(OPTIONS).unwrap();
 //!
 //! assert_eq!(
 //!   "tw-bg-white",
@@ -99,6 +100,49 @@
 //! ```
 //!
 //!
+//! ### Runtime Merge Configuration
+//!
+//! Use [`merge::MergeConfig`] when you need to build the configuration at runtime, need several configurations at once, or need custom collision rules.
+//!
+//! ```
+//! use tailwind_fuse::merge::*;
+//!
+//! let config = MergeConfig::new()
+//!     .prefix(String::from("app-"))
+//!     .collision_id_fn(|elements: &[&str], _: Option<&str>| match elements {
+//!         ["shadow", "brand"] => Some("box-shadow"),
+//!         _ => None,
+//!     });
+//!
+//! assert_eq!(
+//!   "app-shadow-brand",
+//!   tw_merge_config("app-shadow-lg app-shadow-brand", &config),
+//! );
+//! ```
+//!
+//! Use [`merge::MergeTheme`] to register the custom colors, font sizes and spacing from your Tailwind config, so classes like `text-body` are classified correctly.
+//!
+//! With the `config` feature, [`merge::MergeConfig::from_tailwind_config`] loads the theme, `prefix` and `separator` from a JSON or TOML export of your Tailwind config. To skip parsing at runtime, [`build::compile`] generates the same rules from your `build.rs` for [`merge::tw_merge_override`].
+//!
+//! Use [`merge::set_merge_config`] to make it the global default for [`tw_merge!`] and variant macros.
+//!
+//!
+//! ### Tailwind v4
+//!
+//! Set [`merge::TailwindVersion::V4`] to understand v4 syntax, such as `bg-(--brand)`, `bg-red-500!` and the `tw:` prefix.
+//!
+//! ```
+//! use tailwind_fuse::merge::*;
+//!
+//! let config = MergeConfig::new().version(TailwindVersion::V4);
+//!
+//! assert_eq!(
+//!   "bg-(--brand)! inset-shadow-lg",
+//!   tw_merge_config("bg-red-500! inset-shadow-sm bg-(--brand)! inset-shadow-lg", &config),
+//! );
+//! ```
+//!
+//!
 //! ## Usage: Variants
 //!
 //! Useful for building components with first class support for tailwind. By default, conflicts are merged using [`tw_merge()`].
@@ -109,7 +153,8 @@
 //! 1. Base class from [`TwClass`]
 //! 2. Base class from [`TwVariant`]
 //! 3. Enum variant class from [`TwVariant`]
-//! 4. Override class using [`IntoTailwindClass::with_class`] on the struct or builder
+//! 4. Compound variant classes from [`TwClass`]
+//! 5. Override class using [`IntoTailwindClass::with_class`] on the struct or builder
 //!
 //! ```
 //! use tailwind_fuse::*;
@@ -244,6 +289,368 @@
 //!
 //! ```
 //!
+//! #### Boolean and Optional Fields
+//!
+//! Fields of a [`TwClass`] can also be a `bool` with `when_true` and/or `when_false` classes, or an `Option` of a [`TwVariant`] that adds nothing when `None`.
+//! Both default to off (`false` and `None`) in the builder.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwClass)]
+//! #[tw(class = "flex")]
+//! struct Btn {
+//!     icon: Option<BtnIcon>,
+//!     #[tw(when_true = "opacity-50", when_false = "cursor-pointer")]
+//!     disabled: bool,
+//! }
+//!
+//! #[derive(TwVariant)]
+//! enum BtnIcon {
+//!     #[tw(default, class = "pl-2")]
+//!     Left,
+//!     #[tw(class = "pr-2")]
+//!     Right,
+//! }
+//!
+//! assert_eq!(Btn::builder().to_class(), "flex cursor-pointer");
+//! assert_eq!(
+//!     Btn::builder().icon(Some(BtnIcon::Right)).disabled(true).to_class(),
+//!     "flex pr-2 opacity-50"
+//! );
+//! ```
+//!
+//! #### Field Defaults
+//!
+//! Add `default` to a [`TwClass`] field to choose its builder fallback, instead of the [`TwVariant`] default.
+//! [`TwClass`] then implements `Default` with the same fallbacks, so don't also derive it.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwClass)]
+//! struct IconBtn {
+//!     #[tw(default = "Sm")]
+//!     size: BtnSize,
+//! }
+//!
+//! #[derive(TwVariant)]
+//! enum BtnSize {
+//!     #[tw(default, class = "h-9 px-4 py-2")]
+//!     Default,
+//!     #[tw(class = "h-8 px-3")]
+//!     Sm,
+//! }
+//!
+//! assert_eq!(IconBtn::builder().to_class(), "h-8 px-3");
+//! assert_eq!(IconBtn::default().to_class(), "h-8 px-3");
+//! ```
+//!
+//! #### Generics
+//!
+//! [`TwClass`] and [`TwThemeClass`] can be generic. The builder gets the same generics, and needs generic field types to be `Copy` and `Default`.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwClass)]
+//! #[tw(class = "rounded-lg")]
+//! struct Card<T: AsTailwindClass> {
+//!     tone: T,
+//! }
+//!
+//! #[derive(TwVariant)]
+//! enum Tone {
+//!     #[tw(default, class = "bg-white")]
+//!     Light,
+//!     #[tw(class = "bg-black")]
+//!     Dark,
+//! }
+//!
+//! assert_eq!(Card { tone: Tone::Dark }.to_class(), "rounded-lg bg-black");
+//! assert_eq!(Card::<Tone>::builder().to_class(), "rounded-lg bg-white");
+//! ```
+//!
+//! #### Variants with Data
+//!
+//! [`TwVariant`] variants can carry data, with a class template that uses the fields like [`format!`] (`{0}`, `{name}`).
+//! These enums implement [`AsTailwindClassCow`] instead of [`AsTailwindClass`], since the class is built at runtime.
+//! The default variant must be a unit variant. Derive `Clone` (and `Copy` if you can) yourself to use the enum in a [`TwClass`].
+//! Builders are `Copy`, so add `no_copy` to a [`TwClass`] with fields that are only `Clone`, like variants with a `String`.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwVariant, Clone)]
+//! enum BtnColor {
+//!     #[tw(default, class = "bg-blue-500")]
+//!     Blue,
+//!     #[tw(class = "bg-[{0}]")]
+//!     Arbitrary(String),
+//! }
+//!
+//! assert_eq!(BtnColor::Arbitrary("#bada55".into()).as_class_cow(), "bg-[#bada55]");
+//! ```
+//!
+//! #### Compound Variants
+//!
+//! Add `compound` to [`TwClass`] to apply a class when several variants match at once.
+//! Each field takes one variant, or several separated by `|`. Compound classes are merged after the variant classes and before the override class.
+//! An `Option` field matches when it's `Some` of one of the variants, and a `bool` field takes `"true"` or `"false"`.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwClass)]
+//! #[tw(compound(size = "Sm", color = "Red | Blue", class = "font-bold"))]
+//! struct Btn {
+//!     size: BtnSize,
+//!     color: BtnColor,
+//! }
+//!
+//! #[derive(TwVariant)]
+//! enum BtnSize {
+//!     #[tw(default, class = "h-9 px-4 py-2")]
+//!     Default,
+//!     #[tw(class = "h-8 px-3")]
+//!     Sm,
+//! }
+//!
+//! #[derive(TwVariant)]
+//! enum BtnColor {
+//!     #[tw(default, class = "bg-blue-500")]
+//!     Blue,
+//!     #[tw(class = "bg-red-500")]
+//!     Red,
+//! }
+//!
+//! assert_eq!(
+//!     Btn::builder().size(BtnSize::Sm).to_class(),
+//!     "h-8 px-3 bg-blue-500 font-bold"
+//! );
+//! ```
+//!
+//! #### Slots
+//!
+//! Add a `slot` to a [`TwClass`] for each part of a multi-part component, with its own base class.
+//! [`TwVariant`] variants can add a class to any slot, and `slots()` returns the merged classes, with a method per slot that takes an override class.
+//! `bool` fields only apply to the main class, and a slot that a field adds a class to but the [`TwClass`] doesn't declare is a compile error.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwClass)]
+//! #[tw(class = "rounded-xl border")]
+//! #[tw(slot(name = "header", class = "flex flex-col p-6"))]
+//! #[tw(slot(name = "title", class = "font-semibold"))]
+//! struct Card {
+//!     size: CardSize,
+//! }
+//!
+//! #[derive(TwVariant)]
+//! enum CardSize {
+//!     #[tw(default, class = "shadow")]
+//!     Default,
+//!     #[tw(class = "shadow-sm", slot(name = "header", class = "p-4"), slot(name = "title", class = "text-sm"))]
+//!     Sm,
+//! }
+//!
+//! let card = Card { size: CardSize::Sm };
+//! let slots = card.slots();
+//! assert_eq!(card.to_class(), "rounded-xl border shadow-sm");
+//! assert_eq!(slots.header(""), "flex flex-col p-4");
+//! assert_eq!(slots.title("tracking-tight"), "font-semibold text-sm tracking-tight");
+//! ```
+//!
+//! #### Variant Names
+//!
+//! [`TwVariant`] enums can be listed with `VARIANTS`, displayed by name, and parsed from a name with [`str::parse`] (e.g. from a query string).
+//! Names are the snake case variant names (or `rename`), and parsing also accepts the variant identifier, ignoring case.
+//! `variant_name` and `variant_class` return the name and class of a variant, and enums with data-carrying variants only get `variant_name` and `Display`.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwVariant, Debug, PartialEq)]
+//! enum BtnSize {
+//!     #[tw(default, class = "h-9 px-4 py-2")]
+//!     Default,
+//!     #[tw(rename = "small", class = "h-8 px-3")]
+//!     Sm,
+//!     #[tw(class = "h-9 w-9")]
+//!     IconOnly,
+//! }
+//!
+//! for size in BtnSize::VARIANTS {
+//!     println!("{}: {}", size.variant_name(), size.variant_class());
+//! }
+//!
+//! assert_eq!(BtnSize::IconOnly.to_string(), "icon_only");
+//! assert_eq!("small".parse(), Ok(BtnSize::Sm));
+//! assert!("xl".parse::<BtnSize>().is_err());
+//! ```
+//!
+//! #### Compile-time Validation
+//!
+//! Add `strict` to check the class literals of [`TwVariant`] and [`TwClass`] when compiling.
+//! Classes that can't be parsed or don't match a known utility (e.g. `flx`, `z-abc`) are errors.
+//! Classes are checked for Tailwind v3 without a prefix, unless `prefix`, `separator` or `version` are set to match your merge config,
+//! like `strict(prefix = "tw", version = 4)`. Add `colors` to warn about colors outside the default palette (e.g. `bg-bleu-500`),
+//! which may also come from your Tailwind config. The warnings are deprecation warnings, so `deny(deprecated)` makes them errors.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwVariant)]
+//! #[tw(strict, class = "flex")]
+//! enum BtnSize {
+//!     #[tw(default, class = "h-9 px-4 py-2")]
+//!     Default,
+//!     #[tw(class = "h-8 px-3")]
+//!     Sm,
+//! }
+//! ```
+//!
+//! #### Compile-time Merging
+//!
+//! Add `precompute` to a [`TwVariant`] to merge the base class into each variant class when compiling,
+//! instead of on every call. This uses the default merge rules, so don't use it with a custom prefix, theme or collision rules.
+//!
+//! A [`TwClass`] doesn't support `precompute`, since its classes depend on the field values and are merged at runtime.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwVariant)]
+//! #[tw(precompute, class = "px-4 py-2")]
+//! enum BtnSize {
+//!     #[tw(default, class = "h-9")]
+//!     Default,
+//!     #[tw(class = "h-10 px-8")]
+//!     Lg,
+//! }
+//!
+//! assert_eq!(BtnSize::Lg.as_class(), "py-2 h-10 px-8");
+//! ```
+//!
+//! #### Leptos Signals
+//!
+//! The `leptos` feature adds [`tw_merge_signal!`], which merges classes and Leptos signals of classes into a memoised `Signal<String>`.
+//! Add `signals` to a [`TwClass`] to also generate a struct with a signal for each field. It requires Leptos 0.7.
+//! The [demo's `Button`](https://github.com/gaucho-labs/tailwind-fuse/blob/main/example/demo/src/component/button.rs) uses it in place of a `create_memo`.
+//!
+//! ```rust,ignore
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwClass)]
+//! #[tw(signals, class = "flex")]
+//! struct Btn {
+//!     size: BtnSize,
+//! }
+//!
+//! #[derive(TwVariant)]
+//! enum BtnSize {
+//!     #[tw(default, class = "h-9 px-4 py-2")]
+//!     Default,
+//!     #[tw(class = "h-8 px-3")]
+//!     Sm,
+//! }
+//!
+//! // In a component, with `size: Signal<BtnSize>` and `class: Signal<String>` props
+//! # use tailwind_fuse::signal::{Get, Signal};
+//! # let size = Signal::stored(BtnSize::Sm);
+//! # let class = Signal::stored("px-8".to_string());
+//! let class = BtnSignals { size }.with_class(class);
+//! assert_eq!(class.get(), "flex h-8 px-8");
+//!
+//! let class = tw_merge_signal!("flex", size, "px-8");
+//! assert_eq!(class.get(), "flex h-8 px-8");
+//! ```
+//!
+//! #### Serde
+//!
+//! With the `serde` feature, [`TwVariant`] enums implement `Serialize` and `Deserialize` by variant name, and [`TwClass`] builders implement `Deserialize`.
+//! Variants are serialized in snake case (or with `rename`), and deserialized from either name, ignoring case.
+//! Fields missing from a builder fall back to their defaults, so stored configurations turn straight into classes.
+//! Enums with data-carrying variants are not serializable.
+//!
+//! ```rust,ignore
+//! #[derive(TwVariant)]
+//! enum BtnSize {
+//!     #[tw(default, class = "h-9 px-4 py-2")]
+//!     Default,
+//!     #[tw(rename = "small", class = "h-8 px-3")]
+//!     Sm,
+//! }
+//!
+//! let btn: BtnBuilder = serde_json::from_str(r#"{ "size": "small" }"#)?;
+//! assert_eq!(btn.to_class(), "flex h-8 px-3 bg-blue-500 text-blue-100");
+//! ```
+//!
+//! #### Themes
+//!
+//! [`TwThemeClass`] and [`TwThemeVariant`] pick their classes by theme name, with `to_class(theme)` and `as_class(theme)`.
+//! Derive [`TwTheme`] on an enum, and add `theme_enum` to only accept its variants, so a misspelled theme doesn't compile.
+//!
+//! - `extends` starts a theme with the classes of another theme.
+//! - `prefix_with` prefixes the classes of a theme with a Tailwind variant like `dark`, and adds them to the base theme.
+//! - Themes that aren't compiled in are looked up in the [`registry`], for themes loaded at runtime. Components are registered by path.
+//!   Registered classes are leaked, once per distinct class, so reloading a theme doesn't grow memory but new classes do.
+//!
+//! A theme that a component doesn't declare or register uses its base classes.
+//!
+//! ```rust
+//! use tailwind_fuse::*;
+//!
+//! #[derive(TwTheme)]
+//! enum Theme {
+//!     Brand,
+//!     BrandCompact,
+//!     Acme,
+//! }
+//!
+//! #[derive(TwThemeClass)]
+//! #[tw(theme_enum = Theme)]
+//! #[tw(theme(class = "flex gap-2"))]
+//! #[tw(theme(name = brand, extends = base, class = "font-semibold"))]
+//! #[tw(theme(name = brand_compact, extends = brand, class = "gap-1"))]
+//! struct Btn {
+//!     color: BtnColor,
+//! }
+//!
+//! #[derive(TwThemeVariant)]
+//! enum BtnColor {
+//!     #[tw(default)]
+//!     #[tw(theme(class = "bg-white"))]
+//!     #[tw(theme(name = dark, prefix_with = "dark", class = "bg-black"))]
+//!     Default,
+//! }
+//!
+//! let btn = Btn::builder();
+//! assert_eq!(btn.to_class(None::<Theme>), "flex gap-2 bg-white dark:bg-black");
+//! assert_eq!(
+//!     btn.to_class(Some(Theme::BrandCompact)),
+//!     "flex font-semibold gap-1 bg-white dark:bg-black"
+//! );
+//!
+//! registry::register_class("acme", concat!(module_path!(), "::Btn"), "inline-flex");
+//! assert_eq!(btn.to_class(Some(Theme::Acme)), "inline-flex bg-white dark:bg-black");
+//! ```
+//!
+//! #### Dioxus and Yew
+//!
+//! With the `dioxus` or `yew` feature, [`TwClass`] structs and builders can be passed straight to `class`, without calling `to_class`.
+//! They implement Dioxus's `IntoAttributeValue`, and convert into Yew's `Classes` and `AttrValue`.
+//! The `String` returned by [`tw_merge!`] and [`tw_join!`] already works with both.
+//!
+//! ```rust,ignore
+//! // Dioxus
+//! rsx! { button { class: Btn { size: BtnSize::Sm }, "Click" } }
+//!
+//! // Yew
+//! html! { <button class={Btn::builder().size(BtnSize::Sm)}>{ "Click" }</button> }
+//! ```
+//!
 //! #### VSCode Intellisense
 //!
 //! You can enable autocompletion inside `#[tw()]` using the steps below:
@@ -264,12 +671,28 @@
 #[cfg(feature = "variant")]
 pub use variant::*;
 
-pub use crate::core::*;
 pub use crate::core::merge;
+pub use crate::core::*;
+
+/// Leptos signals for Tailwind classes, see [`tw_merge_signal!`].
+#[cfg(feature = "leptos")]
+pub mod signal;
+
+#[cfg(any(feature = "dioxus", feature = "yew", feature = "serde"))]
+pub mod integrations;
+
+/// Themes registered at runtime, used by [`TwThemeClass`] and [`TwThemeVariant`] for themes that aren't compiled in.
+///
+/// Registered classes are returned as `&'static str`, like the compiled classes, so they are leaked and never freed.
+/// Each distinct class is leaked once and reused, so reloading the same themes doesn't use more memory,
+/// but every new class string does. Don't register classes built from untrusted or unbounded input.
+#[cfg(feature = "variant")]
+pub mod registry;
 
-mod ast;
 mod core;
 
+pub(crate) use tailwind_fuse_core::ast;
+
 #[cfg(feature = "variant")]
 mod variant {
     /// Derives a class for use with Tailwind CSS in Rust components.
@@ -282,6 +705,7 @@ mod variant {
     /// - [`TwClass`] base class
     /// - [`TwVariant`] base class
     /// - [`TwVariant`] enum variant class
+    /// - Compound variant classes from `#[tw(compound(...))]`
     /// - Override class with `with_class`
     ///
     /// # Example
@@ -322,6 +746,36 @@ mod variant {
     /// assert_eq!(btn_variant, "flex h-9 px-4 py-2 bg-red-500 text-red-100");
     /// ```
     pub use tailwind_fuse_macro::TwClass;
+    /// Represents the themes of [`TwThemeClass`] and [`TwThemeVariant`], so themes are selected by variant instead of by name.
+    /// Each variant is the snake case name used in `#[tw(theme(name = ...))]`.
+    ///
+    /// Add `theme_enum` to a [`TwThemeClass`] or [`TwThemeVariant`] to check its theme names when compiling.
+    /// Its `to_class` and `as_class` then only accept the enum, and a variant that a component doesn't declare (or isn't registered) selects its base classes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tailwind_fuse::*;
+    ///
+    /// #[derive(TwTheme)]
+    /// enum Theme {
+    ///     Light,
+    ///     HighContrast,
+    /// }
+    ///
+    /// #[derive(TwThemeVariant)]
+    /// #[tw(theme_enum = Theme)]
+    /// enum BtnSize {
+    ///     #[tw(default)]
+    ///     #[tw(theme(class = "h-9"))]
+    ///     #[tw(theme(name = light, class = "px-4"))]
+    ///     #[tw(theme(name = high_contrast, class = "px-4 border-2"))]
+    ///     Default,
+    /// }
+    ///
+    /// assert_eq!("px-4 border-2", BtnSize::Default.as_class(Theme::HighContrast));
+    /// ```
+    pub use tailwind_fuse_macro::TwTheme;
     /// Derives a class for use with Tailwind CSS in Rust components.
     ///
     /// Allows building components with first-class support for Tailwind.
@@ -362,6 +816,57 @@ mod variant {
     /// assert_eq!("flex-col items-center rounded-lg", btn.to_class(Some("default")));
     /// assert_eq!("flex h-10", btn.to_class(None::<&str>))
     /// ```
+    ///
+    /// A theme replaces the base classes, unless it `extends` another theme.
+    /// Then the classes of the whole chain are merged, and fields whose variant doesn't set the theme use the classes of the theme it extends.
+    /// On a [`TwThemeVariant`], `extends` is set on the enum. The theme it extends must be `base` or a declared theme (or a variant of `theme_enum`).
+    /// Its chains are merged on first use with its `merger` (defaults to [`TailwindMerge`]), so set the merge config before rendering.
+    ///
+    /// ```rust
+    /// # use tailwind_fuse::*;
+    /// # #[derive(TwThemeVariant)]
+    /// # enum BtnSize {
+    /// #     #[tw(default)]
+    /// #     #[tw(theme(class = "h-9"))]
+    /// #     #[tw(theme(name = "default", class = "px-4"))]
+    /// #     Default,
+    /// #     #[tw(theme(class = "h-10"))]
+    /// #     #[tw(theme(name = "default", class = "rounded-lg"))]
+    /// #     Lg,
+    /// # }
+    /// #[derive(TwThemeClass)]
+    /// #[tw(theme(class = "flex"))]
+    /// #[tw(theme(name = "default", extends = "base", class = "flex-col items-center"))]
+    /// struct Btn {
+    ///     btn_size: BtnSize,
+    /// }
+    ///
+    /// let btn = Btn { btn_size: BtnSize::Lg };
+    /// assert_eq!("flex flex-col items-center h-10 rounded-lg", btn.to_class(Some("default")));
+    /// ```
+    ///
+    /// Themes that aren't compiled in are looked up in the [`registry`](crate::registry), for themes loaded at runtime.
+    /// Components are registered by path, and themes, components and variants that aren't registered use their base classes.
+    ///
+    /// ```rust
+    /// # use tailwind_fuse::*;
+    /// # #[derive(TwThemeVariant)]
+    /// # enum BtnSize {
+    /// #     #[tw(default)]
+    /// #     #[tw(theme(class = "h-9"))]
+    /// #     Default,
+    /// # }
+    /// # #[derive(TwThemeClass)]
+    /// # #[tw(theme(class = "flex"))]
+    /// # struct Btn {
+    /// #     btn_size: BtnSize,
+    /// # }
+    /// assert_eq!("flex h-9", Btn::builder().to_class(Some("acme")));
+    ///
+    /// registry::register_class("acme", concat!(module_path!(), "::Btn"), "flex bg-pink-500");
+    ///
+    /// assert_eq!("flex bg-pink-500 h-9", Btn::builder().to_class(Some("acme")));
+    /// ```
     pub use tailwind_fuse_macro::TwThemeClass;
     /// Represents a customizable property (variant) of a UI element.
     /// Each variant must be an enum with a default case.
@@ -392,6 +897,26 @@ mod variant {
     /// assert_eq!("text-gray-100 h-9", BtnSize::Default.as_class("base"));
     /// assert_eq!("text-red-500 px-4", BtnSize::Default.as_class("default"));
     /// ```
+    ///
+    /// Add `prefix_with` to a theme to prefix each of its classes with a Tailwind variant (and `separator`, which defaults to `:`).
+    /// Its classes are added to the base theme, so one class has both styles, and the theme name selects the base theme.
+    /// A [`TwThemeClass`] that doesn't declare the theme uses its base class for it, so its fields still get the prefixed classes.
+    ///
+    /// ```rust
+    /// # use tailwind_fuse::*;
+    /// #[derive(TwThemeVariant)]
+    /// enum BtnColor {
+    ///     #[tw(default)]
+    ///     #[tw(theme(class = "bg-white text-black"))]
+    ///     #[tw(theme(name = "dark", prefix_with = "dark", class = "bg-black text-white"))]
+    ///     Default,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     "bg-white text-black dark:bg-black dark:text-white",
+    ///     BtnColor::Default.as_class("base")
+    /// );
+    /// ```
     pub use tailwind_fuse_macro::TwThemeVariant;
     /// Represents a customizable property (variant) of a UI element.
     /// Each variant must be an enum with a default case.
@@ -462,19 +987,59 @@ mod variant {
         fn with_class(&self, class: impl AsRef<str>) -> String;
     }
 
+    /// A theme name, used to select the classes of a [`TwThemeClass`] or [`TwThemeVariant`].
+    /// Implemented automatically for usages of [`TwTheme`], and for `str`.
+    pub trait TailwindTheme {
+        /// The name of the theme, as used in `#[tw(theme(name = ...))]`.
+        fn theme_name(&self) -> &str;
+    }
+
+    impl TailwindTheme for str {
+        fn theme_name(&self) -> &str {
+            self
+        }
+    }
+
+    /// A theme accepted by a [`TwThemeClass`] or [`TwThemeVariant`] whose themes are `T`.
+    /// Implemented for strings when `T` is `str`, and automatically for usages of [`TwTheme`], by value and by reference.
+    pub trait AsTailwindTheme<T: ?Sized> {
+        /// Get the theme.
+        fn as_theme(&self) -> &T;
+    }
+
+    impl<S> AsTailwindTheme<str> for S
+    where
+        S: AsRef<str> + ?Sized,
+    {
+        fn as_theme(&self) -> &str {
+            self.as_ref()
+        }
+    }
+
     /// Used to extract a &str from a type
     pub trait AsTailwindThemeClass {
+        /// The themes accepted by `as_class`, the `theme_enum` if it's set and `str` otherwise.
+        type Theme: TailwindTheme + ?Sized;
         /// Extract a Tailwind class
-        fn as_class(&self, theme: impl AsRef<str>) -> &str;
+        fn as_class(&self, theme: impl AsTailwindTheme<Self::Theme>) -> &str;
+        /// Extract the Tailwind class of the theme named `name`, used by [`TwThemeClass`] for its fields.
+        #[doc(hidden)]
+        fn as_theme_class(&self, name: &str) -> &str;
     }
 
     /// A trait to convert a type into a Tailwind class.
     /// Implemented automatically for usages of [`TwThemeClass`] and [`TwThemeVariant`].
     pub trait IntoTailwindThemeClass {
+        /// The themes accepted by `to_class` and `with_class`, the `theme_enum` if it's set and `str` otherwise.
+        type Theme: TailwindTheme + ?Sized;
         /// Convert the type into a Tailwind class.
-        fn to_class(&self, theme: Option<impl AsRef<str>>) -> String;
+        fn to_class(&self, theme: Option<impl AsTailwindTheme<Self::Theme>>) -> String;
         /// Append to the class (with override precedence) and return the new class.
-        fn with_class(&self, theme: Option<impl AsRef<str>>, class: impl AsRef<str>) -> String;
+        fn with_class(
+            &self,
+            theme: Option<impl AsTailwindTheme<Self::Theme>>,
+            class: impl AsRef<str>,
+        ) -> String;
     }
 
     /// Converts a type into it's builder.
@@ -0,0 +1,160 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// Themes to register with [`register_themes`], by theme name and then component path.
+///
+/// A component is either a [`crate::TwThemeClass`] with its class,
+/// or a [`crate::TwThemeVariant`] with the class of each variant, by snake case variant name.
+///
+/// With the `serde` feature, it deserializes from data like:
+///
+/// ```json
+/// {
+///   "acme": {
+///     "my_app::ui::Btn": "flex rounded-none",
+///     "my_app::ui::BtnSize": { "default": "h-9 px-4", "sm": "h-7 px-2" }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(transparent))]
+pub struct Themes {
+    /// The components of each theme.
+    pub themes: HashMap<String, HashMap<String, ThemeClass>>,
+}
+
+/// The classes of a component in a registered theme, see [`Themes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+pub enum ThemeClass {
+    /// The class of a [`crate::TwThemeClass`].
+    Class(String),
+    /// The class of each variant of a [`crate::TwThemeVariant`].
+    Variants(HashMap<String, String>),
+}
+
+#[derive(Debug, Default)]
+struct Registered {
+    class: Option<&'static str>,
+    variants: HashMap<String, &'static str>,
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    themes: HashMap<String, HashMap<String, Registered>>,
+    // Classes are returned as `&'static str` like the compiled constants, so they're never freed.
+    // Each distinct class is leaked once, and reused when it's registered again.
+    classes: HashSet<&'static str>,
+}
+
+impl Registry {
+    fn register(
+        &mut self,
+        theme: &str,
+        component: &str,
+        class: &str,
+    ) -> (&mut Registered, &'static str) {
+        let class = match self.classes.get(class) {
+            Some(class) => *class,
+            None => {
+                let class: &'static str = Box::leak(class.to_owned().into_boxed_str());
+                self.classes.insert(class);
+                class
+            }
+        };
+        let registered = self
+            .themes
+            .entry(theme.to_owned())
+            .or_default()
+            .entry(component.to_owned())
+            .or_default();
+        (registered, class)
+    }
+}
+
+fn registry() -> &'static RwLock<Registry> {
+    static REGISTRY: OnceLock<RwLock<Registry>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Registers the class of the [`crate::TwThemeClass`] `component` in `theme`.
+///
+/// `component` is the path of the type, like `my_app::ui::Btn`, so components with the same name in
+/// different modules don't collide. Use `concat!(module_path!(), "::Btn")` from the module of `Btn`.
+///
+/// The class is leaked, see [the module docs](self). Each distinct class is kept for the rest of the program,
+/// so registering the same theme again doesn't use more memory.
+pub fn register_class(theme: &str, component: &str, class: &str) {
+    let mut registry = registry().write().unwrap_or_else(PoisonError::into_inner);
+    let (registered, class) = registry.register(theme, component, class);
+    registered.class = Some(class);
+}
+
+/// Registers the class of `variant` (the snake case variant name) of the [`crate::TwThemeVariant`] `component` in `theme`.
+///
+/// `component` is the path of the type, and the class is leaked like in [`register_class`].
+pub fn register_variant_class(theme: &str, component: &str, variant: &str, class: &str) {
+    let mut registry = registry().write().unwrap_or_else(PoisonError::into_inner);
+    let (registered, class) = registry.register(theme, component, class);
+    registered.variants.insert(variant.to_owned(), class);
+}
+
+/// Registers every class in `themes`, leaking each new class like [`register_class`].
+pub fn register_themes(themes: Themes) {
+    for (theme, components) in &themes.themes {
+        for (component, class) in components {
+            match class {
+                ThemeClass::Class(class) => register_class(theme, component, class),
+                ThemeClass::Variants(variants) => {
+                    for (variant, class) in variants {
+                        register_variant_class(theme, component, variant, class);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Whether any class has been registered for `theme`.
+pub fn is_registered(theme: &str) -> bool {
+    let registry = registry().read().unwrap_or_else(PoisonError::into_inner);
+    registry.themes.contains_key(theme)
+}
+
+/// The registered class of the [`crate::TwThemeClass`] `component` in `theme`.
+///
+/// Used by [`crate::TwThemeClass`] for themes that aren't compiled in. When `theme` or the component
+/// isn't registered, returns `fallback` (the base class).
+pub fn class(theme: &str, component: &str, fallback: &'static str) -> &'static str {
+    lookup(theme, component, fallback, |registered| registered.class)
+}
+
+/// The registered class of `variant` of the [`crate::TwThemeVariant`] named `component` in `theme`.
+///
+/// Used by [`crate::TwThemeVariant`] for themes that aren't compiled in. When `theme` or the variant
+/// isn't registered, returns `fallback` (the base class of the variant).
+pub fn variant_class(
+    theme: &str,
+    component: &str,
+    variant: &str,
+    fallback: &'static str,
+) -> &'static str {
+    lookup(theme, component, fallback, |registered| {
+        registered.variants.get(variant).copied()
+    })
+}
+
+fn lookup(
+    theme: &str,
+    component: &str,
+    fallback: &'static str,
+    class: impl FnOnce(&Registered) -> Option<&'static str>,
+) -> &'static str {
+    let registry = registry().read().unwrap_or_else(PoisonError::into_inner);
+    registry
+        .themes
+        .get(theme)
+        .and_then(|components| components.get(component))
+        .and_then(class)
+        .unwrap_or(fallback)
+}
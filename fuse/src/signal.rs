@@ -0,0 +1,113 @@
+use reactive_graph::computed::Memo;
+use reactive_graph::signal::{ReadSignal, RwSignal};
+#[allow(deprecated)]
+use reactive_graph::wrappers::read::MaybeSignal;
+
+pub use reactive_graph::traits::Get;
+pub use reactive_graph::wrappers::read::Signal;
+
+use crate::AsTailwindClassCow;
+
+/// Converts a class, or a signal of a class, into a `Signal<String>`.
+///
+/// Implemented for anything that implements [`AsTailwindClassCow`] (e.g. `&str`, `String` or a `TwVariant`),
+/// and for Leptos signals of those types.
+pub trait IntoClassSignal {
+    /// Convert into a signal of the class.
+    fn into_class_signal(self) -> Signal<String>;
+}
+
+impl<T> IntoClassSignal for T
+where
+    T: AsTailwindClassCow,
+{
+    fn into_class_signal(self) -> Signal<String> {
+        Signal::stored(self.as_class_cow().into_owned())
+    }
+}
+
+impl<T> IntoClassSignal for Signal<T>
+where
+    T: AsTailwindClassCow + Clone + Send + Sync + 'static,
+{
+    fn into_class_signal(self) -> Signal<String> {
+        Signal::derive(move || self.get().as_class_cow().into_owned())
+    }
+}
+
+#[allow(deprecated)]
+impl<T> IntoClassSignal for MaybeSignal<T>
+where
+    T: AsTailwindClassCow + Clone + Send + Sync + 'static,
+{
+    fn into_class_signal(self) -> Signal<String> {
+        Signal::<T>::from(self).into_class_signal()
+    }
+}
+
+impl<T> IntoClassSignal for Memo<T>
+where
+    T: AsTailwindClassCow + Clone + PartialEq + Send + Sync + 'static,
+{
+    fn into_class_signal(self) -> Signal<String> {
+        Signal::<T>::from(self).into_class_signal()
+    }
+}
+
+impl<T> IntoClassSignal for ReadSignal<T>
+where
+    T: AsTailwindClassCow + Clone + Send + Sync + 'static,
+{
+    fn into_class_signal(self) -> Signal<String> {
+        Signal::<T>::from(self).into_class_signal()
+    }
+}
+
+impl<T> IntoClassSignal for RwSignal<T>
+where
+    T: AsTailwindClassCow + Clone + Send + Sync + 'static,
+{
+    fn into_class_signal(self) -> Signal<String> {
+        Signal::<T>::from(self).into_class_signal()
+    }
+}
+
+/// Merges signals of classes into a memoised signal, used by [`crate::tw_merge_signal!`].
+pub fn merge_signals<const N: usize>(classes: [Signal<String>; N]) -> Signal<String> {
+    memo_class(move || {
+        let classes = classes.map(|class| class.get());
+        let classes = classes.iter().map(String::as_str).collect::<Vec<_>>();
+        crate::merge::tw_merge_slice(&classes)
+    })
+}
+
+/// A signal of the class computed by `class`, which is only updated when the class changes.
+pub fn memo_class(class: impl Fn() -> String + Send + Sync + 'static) -> Signal<String> {
+    Memo::new(move |_| class()).into()
+}
+
+/// Like [`crate::tw_merge!`], for classes that change.
+///
+/// Accepts anything that implements [`IntoClassSignal`], like `&str`, `Signal<String>` or `Signal<SomeVariant>`,
+/// and returns a memoised `Signal<String>` that is only updated when the merged class changes.
+///
+/// ```
+/// use reactive_graph::prelude::*;
+/// use reactive_graph::signal::RwSignal;
+/// use tailwind_fuse::tw_merge_signal;
+///
+/// let padding = RwSignal::new("p-2".to_string());
+/// let class = tw_merge_signal!("flex p-4", padding);
+/// assert_eq!(class.get(), "flex p-2");
+///
+/// padding.set("px-2".to_string());
+/// assert_eq!(class.get(), "flex p-4 px-2");
+/// ```
+#[macro_export]
+macro_rules! tw_merge_signal {
+    ($($item:expr),+ $(,)?) => {{
+        $crate::signal::merge_signals([
+            $( $crate::signal::IntoClassSignal::into_class_signal($item), )+
+        ])
+    }};
+}
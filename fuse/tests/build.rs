@@ -0,0 +1,63 @@
+#![cfg(feature = "config")]
+
+use tailwind_fuse::merge::{tw_merge_config, tw_merge_override, MergeConfig};
+
+include!("fixtures/tailwind_merge.rs");
+
+fn tw_merge(class: &str) -> String {
+    tw_merge_override(
+        &[class],
+        TAILWIND_MERGE_OPTIONS,
+        TailwindCollisionId,
+        TailwindCollisions,
+    )
+}
+
+fn config() -> MergeConfig {
+    MergeConfig::from_tailwind_config(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/tailwind.config.json"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn test_generated_is_up_to_date() {
+    let config = config();
+
+    assert_eq!(
+        tailwind_fuse::build::generate(&config),
+        include_str!("fixtures/tailwind_merge.rs")
+    );
+}
+
+#[test]
+fn test_generated_collision_id() {
+    assert_eq!(tw_merge("bg-red-500 bg-primary"), "bg-primary");
+    assert_eq!(
+        tw_merge("text-body text-primary-foreground"),
+        "text-body text-primary-foreground"
+    );
+    assert_eq!(tw_merge("text-sm text-body"), "text-body");
+    assert_eq!(
+        tw_merge("focus-visible:ring-1 focus-visible:ring-ring"),
+        "focus-visible:ring-1 focus-visible:ring-ring"
+    );
+    assert_eq!(tw_merge("p-2 p-4"), "p-4");
+}
+
+#[test]
+fn test_generated_matches_runtime() {
+    let config = config();
+    for class in [
+        "bg-red-500 bg-primary",
+        "border-red-500 border-primary-foreground",
+        "fill-red-500 fill-primary",
+        "text-body text-primary-foreground",
+        "text-sm text-body",
+        "shadow-sm shadow-primary",
+        "ring-1 ring-primary",
+    ] {
+        assert_eq!(tw_merge(class), tw_merge_config(class, &config), "{class}");
+    }
+}
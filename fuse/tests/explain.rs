@@ -0,0 +1,58 @@
+use tailwind_fuse::merge::{tw_merge_explain, tw_merge_explain_config, MergeConfig};
+
+#[test]
+fn test_explain_removed() {
+    let explanation = tw_merge_explain("bg-red-500 hover:bg-blue-500 bg-green-500");
+    assert_eq!(explanation.result, "hover:bg-blue-500 bg-green-500");
+
+    let classes = &explanation.classes;
+    assert_eq!(classes.len(), 3);
+    assert_eq!(classes[0].class, "bg-red-500");
+    assert_eq!(classes[0].collision_id, Ok("background-color"));
+    assert_eq!(classes[0].removed_by, Some(2));
+    assert!(classes[1].is_kept());
+    assert!(classes[2].is_kept());
+
+    let style = classes[1].style.as_ref().unwrap();
+    assert_eq!(style.variants, ["hover"]);
+    assert_eq!(style.elements, ["bg", "blue", "500"]);
+}
+
+#[test]
+fn test_explain_collisions() {
+    let explanation = tw_merge_explain("px-2 py-2 p-4");
+    assert_eq!(explanation.result, "p-4");
+    assert_eq!(explanation.removed_by(0).unwrap().class, "p-4");
+    assert_eq!(explanation.removed_by(1).unwrap().class, "p-4");
+    assert_eq!(explanation.removed_by(2), None);
+}
+
+#[test]
+fn test_explain_arbitrary_property() {
+    let explanation = tw_merge_explain("[color:red] [color:blue]");
+    assert_eq!(explanation.result, "[color:blue]");
+    assert_eq!(explanation.classes[0].collision_id, Ok("color"));
+    assert_eq!(explanation.classes[0].removed_by, Some(1));
+}
+
+#[test]
+fn test_explain_errors() {
+    let explanation = tw_merge_explain("bgi123([(]] unknown-class flex");
+    assert_eq!(explanation.result, "bgi123([(]] unknown-class flex");
+
+    let classes = &explanation.classes;
+    assert_eq!(classes[0].style, None);
+    assert!(classes[0].collision_id.is_err());
+    assert!(classes[1].style.is_some());
+    assert!(classes[1].collision_id.is_err());
+    assert_eq!(classes[2].collision_id, Ok("display"));
+    assert!(classes.iter().all(|class| class.is_kept()));
+}
+
+#[test]
+fn test_explain_config() {
+    let config = MergeConfig::new().prefix("tw-");
+    let explanation = tw_merge_explain_config("tw-p-2 tw-p-4", &config);
+    assert_eq!(explanation.result, "tw-p-4");
+    assert_eq!(explanation.classes[0].removed_by, Some(1));
+}
@@ -0,0 +1,80 @@
+// @generated by tailwind_fuse::build. Do not edit.
+
+/// Prefix, separator and version from the Tailwind config.
+pub const TAILWIND_MERGE_OPTIONS: tailwind_fuse::merge::MergeOptions = tailwind_fuse::merge::MergeOptions {
+    prefix: "",
+    separator: ":",
+    version: tailwind_fuse::merge::TailwindVersion::V3,
+};
+
+/// Collision ids for the classes that use a key from the Tailwind config theme.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TailwindCollisionId;
+
+impl tailwind_fuse::merge::CollisionIdFn for TailwindCollisionId {
+    #[allow(clippy::match_single_binding)] // when the config has no rules
+    fn apply(&self, elements: &[&str], _arbitrary: Option<&str>) -> Option<&'static str> {
+        match elements {
+            ["text", "body"] => Some("font-size"),
+            ["bg", "primary"] => Some("background-color"),
+            ["bg", "primary", "foreground"] => Some("background-color"),
+            ["bg", "ring"] => Some("background-color"),
+            ["text", "primary"] => Some("text-color"),
+            ["text", "primary", "foreground"] => Some("text-color"),
+            ["text", "ring"] => Some("text-color"),
+            ["decoration", "primary"] => Some("text-decoration-color"),
+            ["decoration", "primary", "foreground"] => Some("text-decoration-color"),
+            ["decoration", "ring"] => Some("text-decoration-color"),
+            ["border", "primary"] => Some("border-color"),
+            ["border", "primary", "foreground"] => Some("border-color"),
+            ["border", "ring"] => Some("border-color"),
+            ["divide", "primary"] => Some("divide-color"),
+            ["divide", "primary", "foreground"] => Some("divide-color"),
+            ["divide", "ring"] => Some("divide-color"),
+            ["outline", "primary"] => Some("outline-color"),
+            ["outline", "primary", "foreground"] => Some("outline-color"),
+            ["outline", "ring"] => Some("outline-color"),
+            ["ring", "primary"] => Some("ring-color"),
+            ["ring", "primary", "foreground"] => Some("ring-color"),
+            ["ring", "ring"] => Some("ring-color"),
+            ["shadow", "primary"] => Some("box-shadow-color"),
+            ["shadow", "primary", "foreground"] => Some("box-shadow-color"),
+            ["shadow", "ring"] => Some("box-shadow-color"),
+            ["accent", "primary"] => Some("accent-color"),
+            ["accent", "primary", "foreground"] => Some("accent-color"),
+            ["accent", "ring"] => Some("accent-color"),
+            ["caret", "primary"] => Some("caret-color"),
+            ["caret", "primary", "foreground"] => Some("caret-color"),
+            ["caret", "ring"] => Some("caret-color"),
+            ["fill", "primary"] => Some("fill"),
+            ["fill", "primary", "foreground"] => Some("fill"),
+            ["fill", "ring"] => Some("fill"),
+            ["stroke", "primary"] => Some("stroke"),
+            ["stroke", "primary", "foreground"] => Some("stroke"),
+            ["stroke", "ring"] => Some("stroke"),
+            ["from", "primary"] => Some("from"),
+            ["from", "primary", "foreground"] => Some("from"),
+            ["from", "ring"] => Some("from"),
+            ["via", "primary"] => Some("via"),
+            ["via", "primary", "foreground"] => Some("via"),
+            ["via", "ring"] => Some("via"),
+            ["to", "primary"] => Some("to"),
+            ["to", "primary", "foreground"] => Some("to"),
+            ["to", "ring"] => Some("to"),
+            _ => None,
+        }
+    }
+}
+
+/// Collisions from the Tailwind config, which extend the default collisions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TailwindCollisions;
+
+impl tailwind_fuse::merge::GetCollisionsFn for TailwindCollisions {
+    #[allow(clippy::match_single_binding)] // when the config has no rules
+    fn apply(&self, collision_id: &str) -> Option<Vec<&'static str>> {
+        match collision_id {
+            _ => None,
+        }
+    }
+}
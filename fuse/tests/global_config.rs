@@ -0,0 +1,33 @@
+use tailwind_fuse::merge::{
+    set_merge_config, set_merge_options, tw_merge_override, MergeConfig, MergeConfigAlreadySet,
+    MergeOptions,
+};
+use tailwind_fuse::tw_merge;
+
+#[test]
+fn test_global_config() {
+    assert!(set_merge_config(MergeConfig::new().prefix("tw-")).is_ok());
+
+    // The default options follow the global config.
+    let options = MergeOptions::default();
+    assert_eq!(options.prefix, "tw-");
+    assert_eq!(options.separator, ":");
+
+    let class = "tw-bg-black tw-bg-white";
+    let merged = tw_merge_override(
+        &[class],
+        options,
+        |_: &[&str], _: Option<&str>| None,
+        |_: &str| None,
+    );
+    assert_eq!(merged, "tw-bg-white");
+    assert_eq!(tw_merge!(class), merged);
+
+    // The global config can only be set once.
+    let options = MergeOptions {
+        prefix: "app-",
+        ..options
+    };
+    assert_eq!(set_merge_options(options), Err(MergeConfigAlreadySet));
+    assert_eq!(MergeOptions::default().prefix, "tw-");
+}
@@ -0,0 +1,52 @@
+#![cfg(all(feature = "variant", any(feature = "dioxus", feature = "yew")))]
+
+use tailwind_fuse::*;
+
+#[derive(TwClass)]
+#[tw(class = "flex")]
+struct Btn {
+    size: BtnSize,
+}
+
+#[derive(TwVariant)]
+enum BtnSize {
+    #[tw(default, class = "h-9 px-4 py-2")]
+    Default,
+    #[tw(class = "h-8 px-3")]
+    Sm,
+}
+
+#[cfg(feature = "dioxus")]
+#[test]
+fn test_dioxus() {
+    use tailwind_fuse::integrations::dioxus::{AttributeValue, IntoAttributeValue};
+
+    assert!(matches!(
+        Btn { size: BtnSize::Sm }.into_value(),
+        AttributeValue::Text(class) if class == "flex h-8 px-3"
+    ));
+    assert!(matches!(
+        Btn::builder().into_value(),
+        AttributeValue::Text(class) if class == "flex h-9 px-4 py-2"
+    ));
+    assert!(matches!(
+        tw_merge!("p-2", "p-4").into_value(),
+        AttributeValue::Text(class) if class == "p-4"
+    ));
+}
+
+#[cfg(feature = "yew")]
+#[test]
+fn test_yew() {
+    use tailwind_fuse::integrations::yew::{AttrValue, Classes};
+
+    assert_eq!(
+        Classes::from(Btn { size: BtnSize::Sm }).to_string(),
+        "flex h-8 px-3"
+    );
+    assert_eq!(
+        Classes::from(Btn::builder().size(BtnSize::Sm)),
+        Classes::from(tw_join!("flex", "h-8", "px-3"))
+    );
+    assert_eq!(AttrValue::from(Btn::builder()), "flex h-9 px-4 py-2");
+}
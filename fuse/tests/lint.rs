@@ -0,0 +1,95 @@
+use tailwind_fuse::merge::{tw_lint, tw_lint_config, DiagnosticKind, MergeConfig};
+
+#[test]
+fn test_lint_valid() {
+    assert_eq!(
+        tw_lint("flex hover:bg-red-500 md:p-[2px] [color:red] z-10"),
+        vec![]
+    );
+}
+
+#[test]
+fn test_lint_marker_classes() {
+    assert_eq!(
+        tw_lint("group peer group/item peer/label prose not-prose group-hover/item:flex"),
+        vec![]
+    );
+
+    let diagnostics = tw_lint("groups prose/item");
+    let classes: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.class)
+        .collect();
+    assert_eq!(classes, ["groups", "prose/item"]);
+}
+
+#[test]
+fn test_lint_invalid_value() {
+    let diagnostics = tw_lint("z-10 z-abc basis-x");
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].class, "z-abc");
+    assert_eq!(diagnostics[0].span, 5..10);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::InvalidValue);
+    assert_eq!(diagnostics[0].message, "Invalid z index");
+    assert_eq!(diagnostics[0].suggestion, None);
+
+    assert_eq!(diagnostics[1].class, "basis-x");
+    assert_eq!(diagnostics[1].span, 11..18);
+    assert_eq!(diagnostics[1].kind, DiagnosticKind::InvalidValue);
+}
+
+#[test]
+fn test_lint_unknown_class() {
+    let diagnostics = tw_lint("hover:flx itmes-center jusitfy-between  unknown-class");
+    let suggestions: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.class, diagnostic.suggestion.as_deref()))
+        .collect();
+
+    assert_eq!(
+        suggestions,
+        [
+            ("hover:flx", Some("hover:flex")),
+            ("itmes-center", Some("items-center")),
+            ("jusitfy-between", Some("justify-between")),
+            ("unknown-class", None),
+        ]
+    );
+    assert!(diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.kind == DiagnosticKind::UnknownClass));
+    assert_eq!(diagnostics[3].span, 40..53);
+}
+
+#[test]
+fn test_lint_unparseable() {
+    let diagnostics = tw_lint("flex bg-[red");
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].class, "bg-[red");
+    assert_eq!(diagnostics[0].span, 5..12);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::Unparseable);
+}
+
+#[test]
+fn test_lint_display() {
+    let diagnostics = tw_lint("flx");
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "0..3: Invalid Tailwind class: `flx`, did you mean `flex`?"
+    );
+}
+
+#[test]
+fn test_lint_config() {
+    let config =
+        MergeConfig::new()
+            .prefix("tw-")
+            .collision_id_fn(|elements: &[&str], _: Option<&str>| match elements {
+                ["brand"] => Some("brand"),
+                _ => None,
+            });
+    let diagnostics = tw_lint_config("tw-brand tw-brnd", &config);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].suggestion.as_deref(), Some("tw-brand"));
+}
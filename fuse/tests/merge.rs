@@ -40,6 +40,22 @@ fn test_conflict_with_modifiers() {
     assert_eq!(&result, class)
 }
 
+#[test]
+fn test_conflict_with_reordered_modifiers() {
+    assert_eq!(
+        tw_merge("hover:focus:bg-red-500 focus:hover:bg-blue-500"),
+        "focus:hover:bg-blue-500"
+    );
+    assert_eq!(
+        tw_merge("dark:md:hover:p-2 hover:dark:md:p-4 md:p-8"),
+        "hover:dark:md:p-4 md:p-8"
+    );
+    assert_eq!(
+        tw_merge("hover:focus:[color:red] focus:hover:[color:blue]"),
+        "focus:hover:[color:blue]"
+    );
+}
+
 #[test]
 fn test_conflict_with_arbitrary_values() {
     let class = "m-[2px] m-[10px]";
@@ -394,8 +410,7 @@ fn multiple_arbitrary_variants() {
         tw_merge("[&>*]:[&_div]:underline [&_div]:[&>*]:line-through"),
         "[&>*]:[&_div]:underline [&_div]:[&>*]:line-through"
     );
-    // What is this test even doing?
-    // assert_eq!(tw_merge("hover:dark:[&>*]:focus:disabled:[&_div]:underline dark:hover:[&>*]:disabled:focus:[&_div]:line-through"), "dark:hover:[&>*]:disabled:focus:[&_div]:line-through");
+    assert_eq!(tw_merge("hover:dark:[&>*]:focus:disabled:[&_div]:underline dark:hover:[&>*]:disabled:focus:[&_div]:line-through"), "dark:hover:[&>*]:disabled:focus:[&_div]:line-through");
     assert_eq!(tw_merge("hover:dark:[&>*]:focus:[&_div]:disabled:underline dark:hover:[&>*]:disabled:focus:[&_div]:line-through"), "hover:dark:[&>*]:focus:[&_div]:disabled:underline dark:hover:[&>*]:disabled:focus:[&_div]:line-through");
 }
 
@@ -462,3 +477,79 @@ fn test_negative_values() {
     let result = tw_merge(class);
     assert_eq!(result, "-top-2000");
 }
+
+#[test]
+fn postfix_modifiers() {
+    let class = "bg-red-500/50 bg-blue-500";
+    assert_eq!(tw_merge(class), "bg-blue-500");
+
+    let class = "bg-[#fff]/50 bg-primary/90";
+    assert_eq!(tw_merge(class), "bg-primary/90");
+
+    let class = "bg-black bg-red-500/[.3]";
+    assert_eq!(tw_merge(class), "bg-red-500/[.3]");
+
+    let class = "text-sm/6 text-base/7";
+    assert_eq!(tw_merge(class), "text-base/7");
+
+    let class = "text-red-500 text-base/7";
+    assert_eq!(tw_merge(class), "text-red-500 text-base/7");
+
+    let class = "leading-9 text-sm/[17px]";
+    assert_eq!(tw_merge(class), "text-sm/[17px]");
+
+    let class = "text-lg/7 leading-9";
+    assert_eq!(tw_merge(class), "text-lg/7 leading-9");
+}
+
+#[test]
+fn opacity_modifiers() {
+    let class = "bg-red-500/50 bg-opacity-50";
+    assert_eq!(tw_merge(class), "bg-red-500/50 bg-opacity-50");
+
+    let class = "bg-opacity-25 bg-opacity-50 bg-red-500";
+    assert_eq!(tw_merge(class), "bg-opacity-50 bg-red-500");
+
+    let class = "text-red-500/50 text-opacity-50 border-red-500/50 border-opacity-50";
+    assert_eq!(tw_merge(class), class);
+
+    let class = "ring-primary/50 ring-opacity-50 divide-primary/50 divide-opacity-50";
+    assert_eq!(tw_merge(class), class);
+
+    // Only colors take an opacity modifier.
+    let class = "decoration-primary/50 decoration-red-500";
+    assert_eq!(tw_merge(class), "decoration-red-500");
+
+    let class = "placeholder-primary/50 placeholder-opacity-50 placeholder-red-500";
+    assert_eq!(
+        tw_merge(class),
+        "placeholder-opacity-50 placeholder-red-500"
+    );
+}
+
+#[test]
+fn arbitrary_values_with_slash() {
+    let class = "bg-[url(/img/hero.png)] bg-red-500";
+    assert_eq!(tw_merge(class), "bg-[url(/img/hero.png)] bg-red-500");
+
+    let class = "bg-[rgb(0_0_0/50%)] bg-red-500/50";
+    assert_eq!(tw_merge(class), "bg-red-500/50");
+
+    let class = "w-[calc(100%/3)] w-1/2";
+    assert_eq!(tw_merge(class), "w-1/2");
+
+    let class = "grid-cols-[1fr/2fr] grid-cols-2";
+    assert_eq!(tw_merge(class), "grid-cols-2");
+}
+
+#[test]
+fn fractions() {
+    let class = "w-1/2 w-full";
+    assert_eq!(tw_merge(class), "w-full");
+
+    let class = "inset-1/2 top-1/3 -translate-y-1/2 -translate-y-1/4";
+    assert_eq!(tw_merge(class), "inset-1/2 top-1/3 -translate-y-1/4");
+
+    let class = "basis-1/3 basis-2/3";
+    assert_eq!(tw_merge(class), "basis-2/3");
+}
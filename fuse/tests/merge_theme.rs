@@ -0,0 +1,101 @@
+use tailwind_fuse::merge::{
+    tw_merge, tw_merge_config, tw_merge_override_theme, MergeConfig, MergeOptions, MergeTheme,
+};
+
+fn config() -> MergeConfig {
+    MergeConfig::new().theme(
+        MergeTheme::new()
+            .colors(["primary", "primary-foreground", "ring", "muted"])
+            .font_size(["body", "caption"])
+            .spacing(["gutter"])
+            .font_family(["display"])
+            .box_shadow(["card"]),
+    )
+}
+
+#[test]
+fn test_font_size() {
+    let config = config();
+    assert_eq!(
+        tw_merge_config("text-red-500 text-body", &config),
+        "text-red-500 text-body"
+    );
+    assert_eq!(
+        tw_merge_config("text-lg text-caption", &config),
+        "text-caption"
+    );
+    assert_eq!(
+        tw_merge_config("leading-6 text-body", &config),
+        "text-body",
+        "font size sets line height"
+    );
+
+    // without a theme, text-body is a color
+    assert_eq!(tw_merge("text-red-500 text-body"), "text-body");
+}
+
+#[test]
+fn test_colors() {
+    let config = config();
+    assert_eq!(
+        tw_merge_config("bg-primary text-primary-foreground text-white", &config),
+        "bg-primary text-white"
+    );
+    assert_eq!(
+        tw_merge_config("decoration-2 decoration-muted decoration-primary", &config),
+        "decoration-2 decoration-primary"
+    );
+    assert_eq!(
+        tw_merge_config("shadow-lg shadow-primary", &config),
+        "shadow-lg shadow-primary"
+    );
+}
+
+#[test]
+fn test_spacing() {
+    let config = config();
+    assert_eq!(tw_merge_config("top-2 top-gutter", &config), "top-gutter");
+    assert_eq!(
+        tw_merge_config("inset-0 left-gutter", &config),
+        "inset-0 left-gutter"
+    );
+    assert_eq!(
+        tw_merge_config("basis-1/2 basis-gutter", &config),
+        "basis-gutter"
+    );
+}
+
+#[test]
+fn test_font_family_and_shadow() {
+    let config = config();
+    assert_eq!(
+        tw_merge_config("font-bold font-sans font-display", &config),
+        "font-bold font-display"
+    );
+    assert_eq!(
+        tw_merge_config("shadow-lg shadow-card", &config),
+        "shadow-card"
+    );
+}
+
+#[test]
+fn test_override_theme() {
+    let config = config();
+    let tw_merge = |class: &str| {
+        tw_merge_override_theme(
+            &[class],
+            MergeOptions::default(),
+            config.get_theme(),
+            |_: &[&str], _: Option<&str>| None,
+            |_: &str| None,
+        )
+    };
+
+    for class in [
+        "text-red-500 text-body",
+        "text-lg text-caption",
+        "bg-red-500 bg-primary",
+    ] {
+        assert_eq!(tw_merge(class), tw_merge_config(class, &config), "{class}");
+    }
+}
@@ -1,4 +1,7 @@
-use tailwind_fuse::merge::{tw_merge_options, tw_merge_override, MergeOptions};
+use tailwind_fuse::merge::{
+    tw_merge_config, tw_merge_options, tw_merge_override, tw_merge_slice_config, MergeConfig,
+    MergeOptions, TailwindVersion,
+};
 
 #[test]
 fn test_collisions() {
@@ -44,6 +47,7 @@ fn test_override_config() {
     let config = MergeOptions {
         prefix: "tw-",
         separator: "|",
+        version: TailwindVersion::V3,
     };
 
     let class = "hover|lg|tw-bg-blue-100 hover|lg|tw-bg-red-500";
@@ -57,3 +61,46 @@ fn test_override_config() {
         "No conflict because non-prefix is not considered tailwind class"
     )
 }
+
+#[test]
+fn test_merge_config() {
+    let config = MergeConfig::new()
+        .prefix(format!("{}-", "tw"))
+        .separator("|");
+
+    let class = "hover|lg|tw-bg-blue-100 hover|lg|tw-bg-red-500";
+    let result = tw_merge_config(class, &config);
+    assert_eq!("hover|lg|tw-bg-red-500", result);
+
+    let result = tw_merge_slice_config(&["tw-p-2 tw-px-4", "tw-p-4"], &config);
+    assert_eq!("tw-p-4", result);
+
+    let default = MergeConfig::default();
+    let result = tw_merge_config("hover:p-2 hover:p-4", &default);
+    assert_eq!("hover:p-4", result);
+}
+
+#[test]
+fn test_merge_config_collisions() {
+    let config = MergeConfig::new()
+        .collision_id_fn(|elements: &[&str], _: Option<&str>| match elements {
+            ["bg", "red", _] | ["bg", "white"] | ["bg", "blue", _] => Some("america"),
+            ["flex"] => Some("florida"),
+            _ => None,
+        })
+        .collisions_fn(|collision_id: &str| match collision_id {
+            "florida" => Some(vec!["america"]),
+            _ => None,
+        })
+        .collisions("display", ["text-color"]);
+
+    assert_eq!(
+        "bg-red-500",
+        tw_merge_config("bg-white bg-red-500", &config)
+    );
+    assert_eq!("flex", tw_merge_config("bg-white flex", &config));
+
+    // extra collisions extend the default collisions
+    assert_eq!("grid", tw_merge_config("text-red-500 grid", &config));
+    assert_eq!("p-4", tw_merge_config("px-2 py-2 p-4", &config));
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "variant")]
+
+use tailwind_fuse::registry::{self, ThemeClass, Themes};
+use tailwind_fuse::*;
+
+#[derive(TwThemeClass)]
+#[tw(theme(class = "flex"))]
+#[tw(theme(name = dark, class = "flex bg-black"))]
+struct Btn {
+    size: BtnSize,
+}
+
+#[derive(TwThemeVariant)]
+enum BtnSize {
+    #[tw(default)]
+    #[tw(theme(class = "h-9 px-4"))]
+    Default,
+    #[tw(theme(class = "h-8 px-3"))]
+    IconSm,
+}
+
+#[test]
+fn test_registered_themes() {
+    // Unregistered themes use the base classes.
+    assert_eq!(Btn::builder().to_class(Some("acme")), "flex h-9 px-4");
+
+    registry::register_class("acme", "registry::Btn", "inline-flex bg-pink-500");
+    registry::register_variant_class("acme", "registry::BtnSize", "icon_sm", "h-7 px-2");
+    assert!(registry::is_registered("acme"));
+
+    let btn = Btn::builder();
+    assert_eq!(
+        btn.to_class(Some("acme")),
+        "inline-flex bg-pink-500 h-9 px-4"
+    );
+    assert_eq!(
+        btn.size(BtnSize::IconSm).to_class(Some("acme")),
+        "inline-flex bg-pink-500 h-7 px-2"
+    );
+
+    // Compiled themes are unchanged.
+    assert_eq!(btn.to_class(Some("dark")), "flex bg-black h-9 px-4");
+    assert_eq!(btn.to_class(None::<&str>), "flex h-9 px-4");
+}
+
+#[test]
+fn test_register_themes() {
+    let themes = Themes {
+        themes: [(
+            "tenant".to_string(),
+            [(
+                "registry::BtnSize".to_string(),
+                ThemeClass::Variants([("default".to_string(), "h-10".to_string())].into()),
+            )]
+            .into(),
+        )]
+        .into(),
+    };
+    registry::register_themes(themes);
+
+    assert_eq!(BtnSize::Default.as_class("tenant"), "h-10");
+    assert_eq!(BtnSize::IconSm.as_class("tenant"), "h-8 px-3");
+    assert_eq!(Btn::builder().to_class(Some("tenant")), "flex h-10");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_themes_json() {
+    let themes: Themes = serde_json::from_str(
+        r#"{ "json": { "registry::Btn": "grid", "registry::BtnSize": { "icon_sm": "size-8" } } }"#,
+    )
+    .unwrap();
+    registry::register_themes(themes);
+
+    let btn = Btn::builder().size(BtnSize::IconSm);
+    assert_eq!(btn.to_class(Some("json")), "grid size-8");
+}
+
+mod other {
+    use tailwind_fuse::*;
+
+    #[derive(TwThemeClass)]
+    #[tw(theme(class = "grid"))]
+    pub struct Btn {}
+}
+
+#[test]
+fn test_same_name_in_other_module() {
+    registry::register_class("scoped", "registry::other::Btn", "grid gap-2");
+    registry::register_class("scoped", "registry::other::Btn", "grid gap-2");
+
+    assert_eq!(other::Btn::builder().to_class(Some("scoped")), "grid gap-2");
+    assert_eq!(Btn::builder().to_class(Some("scoped")), "flex h-9 px-4");
+}
+
+#[test]
+fn test_reload_reuses_classes() {
+    registry::register_class("tenant_a", "registry::Btn", "flex gap-4");
+    registry::register_class("tenant_b", "registry::Btn", "flex gap-4");
+    registry::register_class("tenant_a", "registry::Btn", "flex gap-4");
+
+    // Each distinct class is leaked once.
+    let a = registry::class("tenant_a", "registry::Btn", "");
+    let b = registry::class("tenant_b", "registry::Btn", "");
+    assert_eq!(a, "flex gap-4");
+    assert!(std::ptr::eq(a, b));
+}
@@ -0,0 +1,69 @@
+#![cfg(all(feature = "serde", feature = "variant"))]
+
+use tailwind_fuse::*;
+
+#[derive(TwClass)]
+#[tw(class = "flex")]
+struct Btn {
+    size: BtnSize,
+    variant: BtnVariant,
+}
+
+#[derive(TwVariant, Debug, PartialEq)]
+enum BtnSize {
+    #[tw(default, class = "h-9 px-4 py-2")]
+    Default,
+    #[tw(class = "h-8 px-3")]
+    Sm,
+    #[tw(class = "h-9 w-9")]
+    IconOnly,
+}
+
+#[derive(TwVariant, Debug, PartialEq)]
+enum BtnVariant {
+    #[tw(default, class = "bg-blue-500")]
+    Solid,
+    #[tw(rename = "ghost", class = "bg-transparent")]
+    Transparent,
+}
+
+#[test]
+fn test_variant() {
+    assert_eq!(
+        serde_json::to_string(&BtnSize::IconOnly).unwrap(),
+        r#""icon_only""#
+    );
+    assert_eq!(
+        serde_json::to_string(&BtnVariant::Transparent).unwrap(),
+        r#""ghost""#
+    );
+
+    let size = |json| serde_json::from_str::<BtnSize>(json).unwrap();
+    assert_eq!(size(r#""sm""#), BtnSize::Sm);
+    assert_eq!(size(r#""SM""#), BtnSize::Sm);
+    assert_eq!(size(r#""icon_only""#), BtnSize::IconOnly);
+    assert_eq!(size(r#""IconOnly""#), BtnSize::IconOnly);
+    assert_eq!(
+        serde_json::from_str::<BtnVariant>(r#""Ghost""#).unwrap(),
+        BtnVariant::Transparent
+    );
+
+    let error = serde_json::from_str::<BtnSize>(r#""xl""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown variant `xl`, expected one of `default`, `sm`, `icon_only`"
+    );
+}
+
+#[test]
+fn test_builder() {
+    let builder: BtnBuilder =
+        serde_json::from_str(r#"{"size": "sm", "variant": "ghost"}"#).unwrap();
+    assert_eq!(builder.to_class(), "flex h-8 px-3 bg-transparent");
+
+    let builder: BtnBuilder = serde_json::from_str(r#"{"size": "Sm"}"#).unwrap();
+    assert_eq!(builder.to_class(), "flex h-8 px-3 bg-blue-500");
+
+    let builder: BtnBuilder = serde_json::from_str("{}").unwrap();
+    assert_eq!(builder.to_class(), Btn::builder().to_class());
+}
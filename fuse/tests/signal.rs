@@ -0,0 +1,56 @@
+#![cfg(all(feature = "leptos", feature = "variant"))]
+
+use reactive_graph::prelude::*;
+use reactive_graph::signal::RwSignal;
+use tailwind_fuse::signal::Signal;
+use tailwind_fuse::*;
+
+#[derive(TwClass)]
+#[tw(signals, class = "flex")]
+struct Btn {
+    size: BtnSize,
+    #[tw(when_true = "opacity-50")]
+    disabled: bool,
+}
+
+#[derive(TwVariant, PartialEq)]
+enum BtnSize {
+    #[tw(default, class = "h-9 px-4 py-2")]
+    Default,
+    #[tw(class = "h-8 px-3")]
+    Sm,
+}
+
+#[test]
+fn test_tw_merge_signal() {
+    let size = RwSignal::new(BtnSize::Default);
+    let class = RwSignal::new(String::new());
+    let merged = tw_merge_signal!("flex", size, class);
+
+    assert_eq!(merged.get(), "flex h-9 px-4 py-2");
+
+    size.set(BtnSize::Sm);
+    class.set("px-8".to_string());
+    assert_eq!(merged.get(), "flex h-8 px-8");
+}
+
+#[test]
+fn test_signals_derive() {
+    let size = RwSignal::new(BtnSize::Default);
+    let disabled = RwSignal::new(false);
+    let signals = BtnSignals {
+        size: size.into(),
+        disabled: disabled.into(),
+    };
+    let class = signals.with_class(Signal::derive(move || {
+        if disabled.get() { "opacity-75" } else { "" }.to_string()
+    }));
+
+    assert_eq!(class.get(), "flex h-9 px-4 py-2");
+
+    size.set(BtnSize::Sm);
+    disabled.set(true);
+    assert_eq!(class.get(), "flex h-8 px-3 opacity-75");
+
+    assert_eq!(BtnSignals::default().to_class().get(), "flex h-9 px-4 py-2");
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "config")]
+
+use tailwind_fuse::merge::{tw_merge_config, MergeConfig, MergeTheme, TailwindConfigError};
+
+// Subset of `example/demo/tailwind.config.js`
+const DEMO_JSON: &str = r#"{
+    "theme": {
+        "extend": {
+            "fontFamily": {
+                "opensans": ["Open Sans", "sans-serif"]
+            },
+            "fontSize": {
+                "body": ["1rem", { "lineHeight": "1.5rem" }]
+            },
+            "colors": {
+                "ring": "hsl(var(--ring))",
+                "primary": {
+                    "DEFAULT": "hsl(var(--primary))",
+                    "foreground": "hsl(var(--primary-foreground))"
+                }
+            }
+        }
+    }
+}"#;
+
+#[test]
+fn test_json() {
+    let config = MergeConfig::from_tailwind_json(DEMO_JSON).unwrap();
+    assert_eq!(
+        config.get_theme(),
+        &MergeTheme::new()
+            .colors(["primary", "primary-foreground", "ring"])
+            .font_size(["body"])
+            .font_family(["opensans"])
+    );
+
+    assert_eq!(
+        tw_merge_config("bg-red-500 bg-primary", &config),
+        "bg-primary"
+    );
+    assert_eq!(
+        tw_merge_config("text-body text-primary-foreground", &config),
+        "text-body text-primary-foreground"
+    );
+    assert_eq!(
+        tw_merge_config("text-sm text-body text-primary", &config),
+        "text-body text-primary"
+    );
+    assert_eq!(
+        tw_merge_config("ring-1 ring-ring focus-visible:ring-2", &config),
+        "ring-1 ring-ring focus-visible:ring-2"
+    );
+    assert_eq!(
+        tw_merge_config("font-mono font-opensans", &config),
+        "font-opensans"
+    );
+}
+
+#[test]
+fn test_toml() {
+    let config = MergeConfig::from_tailwind_toml(
+        r##"
+        prefix = "tw-"
+        separator = "_"
+
+        [theme.spacing]
+        gutter = "2rem"
+
+        [theme.extend.colors.primary]
+        DEFAULT = "#000"
+        foreground = "#fff"
+        "##,
+    )
+    .unwrap();
+
+    assert_eq!(config.get_prefix(), "tw-");
+    assert_eq!(config.get_separator(), "_");
+    assert_eq!(
+        tw_merge_config("hover_tw-p-4 hover_tw-p-gutter", &config),
+        "hover_tw-p-gutter"
+    );
+    assert_eq!(
+        tw_merge_config("tw-text-body tw-text-primary-foreground", &config),
+        "tw-text-primary-foreground"
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert!(matches!(
+        MergeConfig::from_tailwind_json("{"),
+        Err(TailwindConfigError::Parse(_))
+    ));
+    assert!(matches!(
+        MergeConfig::from_tailwind_json(r#"{ "prefix": 1 }"#),
+        Err(TailwindConfigError::Invalid(_))
+    ));
+    assert!(matches!(
+        MergeConfig::from_tailwind_json(r#"{ "theme": { "extend": { "colors": [] } } }"#),
+        Err(TailwindConfigError::Invalid(_))
+    ));
+    assert!(matches!(
+        MergeConfig::from_tailwind_config("missing.json"),
+        Err(TailwindConfigError::Io(_))
+    ));
+}
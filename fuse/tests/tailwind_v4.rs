@@ -0,0 +1,101 @@
+use tailwind_fuse::merge::{
+    tw_merge_config, tw_merge_options, MergeConfig, MergeOptions, TailwindVersion,
+};
+
+const V4: MergeOptions = MergeOptions {
+    prefix: "",
+    separator: ":",
+    version: TailwindVersion::V4,
+};
+
+fn tw_merge(class: &str) -> String {
+    tw_merge_options(class, V4)
+}
+
+#[test]
+fn test_css_variable_shorthand() {
+    assert_eq!(tw_merge("bg-red-500 bg-(--brand)"), "bg-(--brand)");
+    assert_eq!(tw_merge("text-(--fg) text-red-500"), "text-red-500");
+    assert_eq!(
+        tw_merge("text-lg text-(length:--size)"),
+        "text-(length:--size)"
+    );
+    assert_eq!(tw_merge("z-10 z-(--layer)"), "z-(--layer)");
+    assert_eq!(tw_merge("p-2 p-(--gutter)"), "p-(--gutter)");
+}
+
+#[test]
+fn test_important_suffix() {
+    assert_eq!(tw_merge("bg-red-500! bg-blue-500!"), "bg-blue-500!");
+    assert_eq!(tw_merge("!bg-red-500 bg-blue-500!"), "bg-blue-500!");
+    assert_eq!(
+        tw_merge("bg-red-500! bg-blue-500"),
+        "bg-red-500! bg-blue-500"
+    );
+}
+
+#[test]
+fn test_prefix() {
+    let config = MergeConfig::new().prefix("tw").version(TailwindVersion::V4);
+    assert_eq!(
+        tw_merge_config("tw:hover:p-2 tw:hover:p-4 tw:p-1", &config),
+        "tw:hover:p-4 tw:p-1"
+    );
+    assert_eq!(
+        tw_merge_config("tw:bg-red-500 bg-blue-500", &config),
+        "tw:bg-red-500 bg-blue-500",
+        "classes without prefix are not Tailwind classes"
+    );
+}
+
+#[test]
+fn test_new_utilities() {
+    assert_eq!(
+        tw_merge("inset-shadow-sm inset-shadow-lg"),
+        "inset-shadow-lg"
+    );
+    assert_eq!(
+        tw_merge("inset-shadow-sm inset-shadow-red-500"),
+        "inset-shadow-sm inset-shadow-red-500"
+    );
+    assert_eq!(tw_merge("inset-ring inset-ring-2"), "inset-ring-2");
+    assert_eq!(
+        tw_merge("shadow-sm inset-shadow-sm"),
+        "shadow-sm inset-shadow-sm"
+    );
+    assert_eq!(
+        tw_merge("text-shadow-sm text-shadow-lg text-red-500"),
+        "text-shadow-lg text-red-500"
+    );
+    assert_eq!(tw_merge("bg-linear-to-r bg-radial"), "bg-radial");
+    assert_eq!(tw_merge("outline-none outline-hidden"), "outline-hidden");
+    assert_eq!(
+        tw_merge("rotate-x-12 rotate-x-45 rotate-45"),
+        "rotate-x-45 rotate-45"
+    );
+    assert_eq!(tw_merge("translate-x-2 translate-4"), "translate-4");
+    assert_eq!(
+        tw_merge("translate-4 translate-x-2"),
+        "translate-4 translate-x-2"
+    );
+    assert_eq!(tw_merge("scheme-light scheme-dark"), "scheme-dark");
+    assert_eq!(
+        tw_merge("field-sizing-fixed field-sizing-content"),
+        "field-sizing-content"
+    );
+    assert_eq!(
+        tw_merge("font-stretch-50% font-bold"),
+        "font-stretch-50% font-bold"
+    );
+    assert_eq!(tw_merge("wrap-anywhere wrap-break-word"), "wrap-break-word");
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(tw_merge("@md:flex @md:grid"), "@md:grid");
+    assert_eq!(tw_merge("*:p-2 *:p-4"), "*:p-4");
+    assert_eq!(
+        tw_merge("not-hover:flex not-hover:grid hover:flex"),
+        "not-hover:grid hover:flex"
+    );
+}
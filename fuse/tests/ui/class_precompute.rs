@@ -0,0 +1,15 @@
+use tailwind_fuse::*;
+
+#[derive(TwVariant)]
+enum Size {
+    #[tw(default, class = "h-9")]
+    Sm,
+}
+
+#[derive(TwClass)]
+#[tw(precompute, class = "flex")]
+struct Btn {
+    size: Size,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use tailwind_fuse::*;
+
+#[derive(TwVariant)]
+enum Size {
+    #[tw(default, class = "h-9", slot(name = "icn", class = "size-4"))]
+    Sm,
+}
+
+#[derive(TwClass)]
+#[tw(class = "flex", slot(name = "icon", class = "shrink-0"))]
+struct Btn {
+    size: Size,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![deny(deprecated)]
+
+use tailwind_fuse::*;
+
+#[derive(TwVariant)]
+#[tw(strict(colors), class = "bg-bleu-500")]
+enum Color {
+    #[tw(default, class = "text-white")]
+    Default,
+}
+
+#[derive(TwVariant)]
+#[tw(strict(version = 5), class = "flex")]
+enum Size {
+    #[tw(default, class = "h-9")]
+    Default,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use tailwind_fuse::*;
+
+#[derive(TwTheme)]
+enum Theme {
+    Fancy,
+}
+
+#[derive(TwThemeVariant)]
+#[tw(theme_enum = Theme)]
+enum Size {
+    #[tw(default)]
+    #[tw(theme(class = "h-9"))]
+    #[tw(theme(name = fancy, class = "h-10"))]
+    Sm,
+}
+
+#[derive(TwThemeClass)]
+#[tw(theme_enum = Theme)]
+#[tw(theme(class = "flex"))]
+#[tw(theme(name = fancy, class = "grid"))]
+struct Card {
+    size: Size,
+}
+
+fn main() {
+    Size::Sm.as_class("fnacy");
+    Card::builder().to_class(Some("fnacy"));
+}
@@ -120,6 +120,623 @@ mod test {
         );
     }
 
+    #[test]
+    fn strict() {
+        #[derive(TwVariant)]
+        #[tw(
+            strict,
+            class = "inline-flex focus-visible:ring-1 [mask-type:luminance]"
+        )]
+        enum Size {
+            #[tw(default, class = "h-9 px-4 aspect-16/9 text-sm/6 bg-red-500/50")]
+            Sm,
+            #[tw(class = "h-10 z-[5] bg-[#fff] ring-offset-white")]
+            Lg,
+        }
+
+        #[derive(TwClass)]
+        #[tw(strict, class = "rounded-md text-white")]
+        struct Btn {
+            size: Size,
+        }
+
+        assert_eq!(
+            Btn { size: Size::Lg }.to_class(),
+            "rounded-md text-white inline-flex focus-visible:ring-1 [mask-type:luminance] h-10 z-[5] bg-[#fff] ring-offset-white"
+        );
+    }
+
+    #[test]
+    fn strict_options() {
+        // Colors from a Tailwind config are only reported with `colors`.
+        #[derive(TwVariant)]
+        #[tw(strict(prefix = "tw-"), class = "tw-flex hover:tw-bg-brand-500")]
+        enum Size {
+            #[tw(default, class = "tw-h-9 md:tw-px-4")]
+            Sm,
+        }
+
+        #[derive(TwClass)]
+        #[tw(
+            strict(prefix = "tw", version = 4),
+            class = "tw:flex tw:bg-(--brand)/50"
+        )]
+        struct Btn {
+            #[tw(when_true = "tw:hover:rounded-md")]
+            round: bool,
+        }
+
+        assert_eq!(
+            Size::Sm.as_class(),
+            "tw-flex hover:tw-bg-brand-500 tw-h-9 md:tw-px-4"
+        );
+        assert_eq!(
+            Btn { round: true }.to_class(),
+            "tw:flex tw:bg-(--brand)/50 tw:hover:rounded-md"
+        );
+    }
+
+    #[test]
+    fn precompute() {
+        #[derive(TwVariant)]
+        #[tw(precompute, class = "px-4 py-2 text-white")]
+        enum Size {
+            #[tw(default, class = "px-8")]
+            Lg,
+            #[tw(class = "p-2 hover:px-2")]
+            Sm,
+        }
+
+        assert_eq!(Size::Lg.as_class(), "py-2 text-white px-8");
+        assert_eq!(Size::Sm.as_class(), "text-white p-2 hover:px-2");
+    }
+
+    #[test]
+    fn compound() {
+        #[derive(TwVariant, PartialEq)]
+        enum Variant {
+            #[tw(default, class = "bg-blue-500")]
+            Solid,
+            #[tw(class = "border border-blue-500")]
+            Outline,
+            #[tw(class = "bg-transparent")]
+            Ghost,
+        }
+
+        #[derive(TwClass)]
+        #[tw(class = "inline-flex")]
+        #[tw(compound(size = "Sm", variant = "Outline | Ghost", class = "px-2"))]
+        #[tw(compound(size = "Lg", variant = "Outline", class = "border-2"))]
+        struct Btn {
+            size: BtnSize,
+            variant: Variant,
+        }
+
+        let btn = |size, variant| Btn { size, variant };
+
+        assert_eq!(
+            btn(BtnSize::Sm, Variant::Outline).to_class(),
+            "inline-flex h-8 rounded-md text-xs border border-blue-500 px-2"
+        );
+        assert_eq!(
+            btn(BtnSize::Sm, Variant::Ghost).with_class("px-1"),
+            "inline-flex h-8 rounded-md text-xs bg-transparent px-1"
+        );
+        assert_eq!(
+            btn(BtnSize::Sm, Variant::Solid).to_class(),
+            "inline-flex h-8 rounded-md px-3 text-xs bg-blue-500"
+        );
+        assert_eq!(
+            btn(BtnSize::Lg, Variant::Outline).to_class(),
+            "inline-flex h-10 rounded-lg px-8 border-blue-500 border-2"
+        );
+    }
+
+    #[test]
+    fn bool_and_option_fields() {
+        #[derive(TwClass)]
+        #[tw(class = "inline-flex")]
+        struct Btn {
+            size: BtnSize,
+            color: Option<BtnColor>,
+            #[tw(when_true = "opacity-50 pointer-events-none")]
+            disabled: bool,
+            #[tw(when_true = "w-full", when_false = "w-auto")]
+            full_width: bool,
+        }
+
+        assert_eq!(
+            Btn::builder().to_class(),
+            "inline-flex h-9 px-4 py-2 w-auto"
+        );
+        assert_eq!(
+            Btn::builder()
+                .color(Some(BtnColor::Red))
+                .disabled(true)
+                .full_width(true)
+                .with_class("opacity-75"),
+            "inline-flex h-9 px-4 py-2 bg-red-500 text-red-100 pointer-events-none w-full opacity-75"
+        );
+
+        let btn = Btn {
+            size: BtnSize::Sm,
+            color: None,
+            disabled: false,
+            full_width: true,
+        };
+        assert_eq!(
+            btn.to_class(),
+            "inline-flex h-8 rounded-md px-3 text-xs w-full"
+        );
+    }
+
+    #[test]
+    fn compound_option_and_bool_fields() {
+        #[derive(TwClass)]
+        #[tw(compound(color = "Red", disabled = "true", class = "bg-red-300"))]
+        #[tw(compound(size = "Sm", disabled = "false", class = "hover:opacity-90"))]
+        struct Btn {
+            size: BtnSize,
+            color: Option<BtnColor>,
+            #[tw(when_true = "opacity-50")]
+            disabled: bool,
+        }
+
+        assert_eq!(
+            Btn::builder()
+                .color(Some(BtnColor::Red))
+                .disabled(true)
+                .to_class(),
+            "h-9 px-4 py-2 text-red-100 opacity-50 bg-red-300"
+        );
+        assert_eq!(
+            Btn::builder().color(Some(BtnColor::Red)).to_class(),
+            "h-9 px-4 py-2 bg-red-500 text-red-100"
+        );
+        assert_eq!(
+            Btn::builder().disabled(true).to_class(),
+            "h-9 px-4 py-2 opacity-50"
+        );
+        assert_eq!(
+            Btn::builder().size(BtnSize::Sm).to_class(),
+            "h-8 rounded-md px-3 text-xs hover:opacity-90"
+        );
+    }
+
+    #[test]
+    fn field_default() {
+        #[derive(TwClass)]
+        struct IconBtn {
+            #[tw(default = "Sm")]
+            size: BtnSize,
+            #[tw(default = "Red")]
+            color: Option<BtnColor>,
+            #[tw(default = "true", when_true = "rounded-full")]
+            round: bool,
+        }
+
+        assert_eq!(
+            IconBtn::builder().to_class(),
+            "h-8 px-3 text-xs bg-red-500 text-red-100 rounded-full"
+        );
+        assert_eq!(
+            IconBtn::default().with_class("rounded-none"),
+            "h-8 px-3 text-xs bg-red-500 text-red-100 rounded-none"
+        );
+        assert_eq!(
+            IconBtn::builder()
+                .size(BtnSize::Lg)
+                .color(None)
+                .round(false)
+                .to_class(),
+            "h-10 rounded-lg px-8"
+        );
+    }
+
+    #[test]
+    fn generics() {
+        trait CardTone: AsTailwindClass + Copy + Default {}
+        impl CardTone for BtnColor {}
+
+        #[derive(TwClass)]
+        #[tw(class = "rounded-lg")]
+        struct Card<'a, T: CardTone>
+        where
+            T: 'static,
+        {
+            tone: T,
+            title: Option<&'a str>,
+        }
+
+        let card = Card {
+            tone: BtnColor::Red,
+            title: Some("font-bold"),
+        };
+        assert_eq!(
+            card.to_class(),
+            "rounded-lg bg-red-500 text-red-100 font-bold"
+        );
+
+        let builder = card.into_builder().title(None);
+        assert_eq!(
+            builder.with_class("p-4"),
+            "rounded-lg bg-red-500 text-red-100 p-4"
+        );
+        assert_eq!(
+            Card::<BtnColor>::builder().to_class(),
+            "rounded-lg bg-blue-500 text-blue-100"
+        );
+    }
+
+    #[test]
+    fn theme_generics() {
+        #[derive(TwThemeVariant)]
+        enum Size {
+            #[tw(default)]
+            #[tw(theme(class = "h-9"))]
+            #[tw(theme(name = "dark", class = "h-8"))]
+            Sm,
+            #[tw(theme(class = "h-10"))]
+            Lg,
+        }
+
+        #[derive(TwThemeClass)]
+        #[tw(theme(class = "flex"))]
+        #[tw(theme(name = "dark", class = "bg-black"))]
+        struct Card<T: AsTailwindThemeClass> {
+            size: T,
+        }
+
+        let card = Card { size: Size::Lg };
+        assert_eq!(card.to_class(None::<&str>), "flex h-10");
+        assert_eq!(
+            Card::<Size>::builder().with_class(Some("dark"), "p-4"),
+            "bg-black h-8 p-4"
+        );
+    }
+
+    #[test]
+    fn theme_enum() {
+        #[derive(TwTheme, Debug, PartialEq)]
+        enum Theme {
+            Light,
+            HighContrast,
+        }
+
+        #[derive(TwThemeVariant)]
+        #[tw(theme_enum = Theme)]
+        enum Size {
+            #[tw(default)]
+            #[tw(theme(class = "h-9"))]
+            #[tw(theme(name = high_contrast, class = "h-10 border-2"))]
+            Sm,
+        }
+
+        #[derive(TwThemeClass)]
+        #[tw(theme_enum = Theme)]
+        #[tw(theme(class = "flex"))]
+        #[tw(theme(name = light, class = "bg-white"))]
+        #[tw(theme(name = high_contrast, class = "bg-black"))]
+        struct Card {
+            size: Size,
+        }
+
+        assert_eq!(Theme::HighContrast.theme_name(), "high_contrast");
+
+        let card = Card::builder();
+        assert_eq!(card.to_class(None::<Theme>), "flex h-9");
+        assert_eq!(card.to_class(Some(Theme::Light)), "bg-white h-9");
+        assert_eq!(
+            card.to_class(Some(Theme::HighContrast)),
+            "bg-black h-10 border-2"
+        );
+        assert_eq!(
+            card.to_class(Some(&Theme::HighContrast)),
+            card.to_class(Some(Theme::HighContrast))
+        );
+        assert_eq!(Size::Sm.as_class(Theme::Light), "h-9");
+    }
+
+    #[test]
+    fn theme_extends() {
+        #[derive(TwThemeVariant)]
+        #[tw(theme(class = "rounded"))]
+        #[tw(theme(name = dark, extends = base, class = "shadow-none"))]
+        #[tw(theme(name = dim, extends = dark))]
+        enum Size {
+            #[tw(default)]
+            #[tw(theme(class = "h-9 px-4"))]
+            #[tw(theme(name = dark, class = "px-3"))]
+            Sm,
+            #[tw(theme(class = "h-10"))]
+            #[tw(theme(name = dim, class = "opacity-75"))]
+            Lg,
+        }
+
+        assert_eq!(Size::Sm.as_class("dark"), "rounded h-9 shadow-none px-3");
+        assert_eq!(Size::Lg.as_class("dark"), "rounded h-10 shadow-none");
+        assert_eq!(
+            Size::Lg.as_class("dim"),
+            "rounded h-10 shadow-none opacity-75"
+        );
+
+        #[derive(TwThemeClass)]
+        #[tw(theme(class = "flex"))]
+        #[tw(theme(name = dark, extends = base, class = "bg-black"))]
+        #[tw(theme(name = light, class = "bg-white"))]
+        struct Card {
+            size: Size,
+        }
+
+        let card = Card::builder();
+        assert_eq!(
+            card.to_class(Some("dark")),
+            "flex bg-black rounded h-9 shadow-none px-3"
+        );
+        assert_eq!(card.to_class(Some("light")), "bg-white rounded h-9 px-4");
+        assert_eq!(card.to_class(None::<&str>), "flex rounded h-9 px-4");
+    }
+
+    #[test]
+    fn theme_extends_merger() {
+        use std::sync::OnceLock;
+        use tailwind_fuse::merge::{tw_merge_slice_config, MergeConfig, MergeTheme};
+
+        struct BrandMerge;
+
+        impl TailwindFuse for BrandMerge {
+            fn fuse_classes(&self, class: &[&str]) -> String {
+                static CONFIG: OnceLock<MergeConfig> = OnceLock::new();
+                let config = CONFIG.get_or_init(|| {
+                    MergeConfig::new()
+                        .prefix("tw-")
+                        .theme(MergeTheme::new().font_size(["body"]))
+                });
+                tw_merge_slice_config(class, config)
+            }
+        }
+
+        // An extends chain is merged with the configured prefix and theme.
+        #[derive(TwThemeVariant)]
+        #[tw(merger = BrandMerge)]
+        #[tw(theme(name = brand, extends = base))]
+        enum Text {
+            #[tw(default)]
+            #[tw(theme(class = "tw-text-body tw-p-2"))]
+            #[tw(theme(name = brand, class = "tw-text-red-500 tw-p-4"))]
+            Default,
+        }
+
+        assert_eq!(Text::Default.as_class("base"), "tw-text-body tw-p-2");
+        assert_eq!(
+            Text::Default.as_class("brand"),
+            "tw-text-body tw-text-red-500 tw-p-4"
+        );
+    }
+
+    #[test]
+    fn theme_prefix_with() {
+        #[derive(TwThemeVariant)]
+        #[tw(theme(class = "bg-white"))]
+        #[tw(theme(name = dark, prefix_with = "dark", class = "bg-black"))]
+        enum Color {
+            #[tw(default)]
+            #[tw(theme(class = "text-gray-900 hover:text-black"))]
+            #[tw(theme(name = dark, prefix_with = "dark", class = "text-gray-100 hover:text-white"))]
+            Gray,
+            #[tw(theme(class = "text-blue-500"))]
+            Blue,
+        }
+
+        assert_eq!(
+            Color::Gray.as_class("base"),
+            "bg-white dark:bg-black text-gray-900 hover:text-black dark:text-gray-100 dark:hover:text-white"
+        );
+        assert_eq!(Color::Gray.as_class("dark"), Color::Gray.as_class("base"));
+        assert_eq!(
+            Color::Blue.as_class("dark"),
+            "bg-white dark:bg-black text-blue-500"
+        );
+
+        #[derive(TwThemeClass)]
+        #[tw(theme(class = "flex"))]
+        #[tw(theme(name = dark, prefix_with = "tw-dark", separator = "_", class = "shadow-none"))]
+        struct Card {
+            color: Color,
+        }
+
+        let card = Card::builder().color(Color::Blue);
+        assert_eq!(
+            card.to_class(None::<&str>),
+            "flex tw-dark_shadow-none bg-white dark:bg-black text-blue-500"
+        );
+        assert_eq!(card.to_class(Some("dark")), card.to_class(None::<&str>));
+
+        // Only the field declares `dark`, so the struct uses its base class for it.
+        #[derive(TwThemeClass)]
+        #[tw(theme(class = "flex"))]
+        struct Badge {
+            color: Color,
+        }
+
+        let badge = Badge::builder();
+        assert_eq!(
+            badge.to_class(Some("dark")),
+            "flex bg-white dark:bg-black text-gray-900 hover:text-black dark:text-gray-100 dark:hover:text-white"
+        );
+        assert_eq!(badge.to_class(Some("dark")), badge.to_class(None::<&str>));
+    }
+
+    #[test]
+    fn data_variants() {
+        #[derive(TwVariant, Clone, Copy)]
+        #[tw(class = "border")]
+        enum Color {
+            #[tw(default, class = "bg-white")]
+            White,
+            #[tw(class = "bg-[{0}]")]
+            Arbitrary(&'static str),
+        }
+
+        assert_eq!(Color::White.as_class_cow(), "border bg-white");
+        assert_eq!(Color::Arbitrary("#fff").as_class_cow(), "border bg-[#fff]");
+
+        #[derive(TwClass)]
+        #[tw(class = "p-4")]
+        struct Card {
+            size: BtnSize,
+            color: Color,
+        }
+
+        let card = Card {
+            size: BtnSize::Sm,
+            color: Color::Arbitrary("#000"),
+        };
+        assert_eq!(
+            card.with_class("border-0"),
+            "p-4 h-8 rounded-md px-3 text-xs bg-[#000] border-0"
+        );
+
+        #[derive(TwVariant, Clone)]
+        enum Text {
+            #[tw(default, class = "text-black")]
+            Black,
+            #[tw(class = "text-{name}-{shade:03}")]
+            Palette { name: String, shade: u16 },
+        }
+
+        let palette = Text::Palette {
+            name: "red".to_string(),
+            shade: 50,
+        };
+        assert_eq!(palette.as_class_cow(), "text-red-050");
+        assert_eq!(
+            tw_merge!("text-white", palette.as_class_cow()),
+            "text-red-050"
+        );
+
+        #[derive(TwClass)]
+        #[tw(no_copy, class = "text-sm")]
+        struct Label {
+            text: Text,
+        }
+
+        let label = Label::builder().text(palette);
+        assert_eq!(label.to_class(), "text-sm text-red-050");
+        assert_eq!(label.with_class("text-lg"), "text-red-050 text-lg");
+        assert_eq!(Label::builder().to_class(), "text-sm text-black");
+    }
+
+    #[test]
+    fn variant_names() {
+        #[derive(TwVariant, Debug, PartialEq)]
+        #[tw(class = "rounded")]
+        enum Size {
+            #[tw(default, class = "h-9")]
+            Default,
+            #[tw(class = "h-8")]
+            IconSm,
+            #[tw(rename = "large", class = "h-10")]
+            Lg,
+        }
+
+        assert_eq!(Size::VARIANTS, &[Size::Default, Size::IconSm, Size::Lg]);
+        let names = Size::VARIANTS
+            .iter()
+            .map(|size| (size.variant_name(), size.variant_class()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("default", "rounded h-9"),
+                ("icon_sm", "rounded h-8"),
+                ("large", "rounded h-10")
+            ]
+        );
+        assert_eq!(Size::IconSm.to_string(), "icon_sm");
+
+        assert_eq!("icon_sm".parse(), Ok(Size::IconSm));
+        assert_eq!("IconSm".parse(), Ok(Size::IconSm));
+        assert_eq!("LARGE".parse(), Ok(Size::Lg));
+        let error = "xl".parse::<Size>().unwrap_err();
+        assert_eq!(error.variant(), "xl");
+        assert_eq!(
+            error.to_string(),
+            "unknown variant `xl`, expected one of `default`, `icon_sm`, `large`"
+        );
+
+        #[derive(TwVariant)]
+        enum Color {
+            #[tw(default, class = "bg-white")]
+            White,
+            #[tw(class = "bg-[{0}]")]
+            Arbitrary(String),
+        }
+        assert_eq!(
+            Color::Arbitrary("#000".to_string()).to_string(),
+            "arbitrary"
+        );
+    }
+
+    #[test]
+    fn slots() {
+        #[derive(TwVariant)]
+        enum CardSize {
+            #[tw(default, class = "p-4")]
+            #[tw(
+                slot(name = "header", class = "gap-2"),
+                slot(name = "title", class = "text-lg")
+            )]
+            Default,
+            #[tw(class = "p-2", slot(name = "title", class = "text-sm"))]
+            #[tw(slot(name = "title", class = "font-medium"))]
+            Sm,
+        }
+
+        #[derive(TwClass)]
+        #[tw(class = "rounded-xl border")]
+        #[tw(slot(name = "header", class = "flex flex-col gap-1.5"))]
+        #[tw(slot(name = "title", class = "font-semibold"))]
+        #[tw(slot(name = "content"))]
+        struct Card {
+            size: CardSize,
+            color: Option<BtnColor>,
+        }
+
+        let slots = Card::builder().slots();
+        assert_eq!(slots.header(""), "flex flex-col gap-2");
+        assert_eq!(
+            slots.title("tracking-tight"),
+            "font-semibold text-lg tracking-tight"
+        );
+        assert_eq!(slots.content("pt-0"), "pt-0");
+
+        let card = Card {
+            size: CardSize::Sm,
+            color: Some(BtnColor::Red),
+        };
+        let slots = card.slots();
+        assert_eq!(slots.header(""), "flex flex-col gap-1.5");
+        assert_eq!(slots.title(""), "text-sm font-medium");
+        assert_eq!(
+            card.to_class(),
+            "rounded-xl border p-2 bg-red-500 text-red-100"
+        );
+        assert_eq!(CardSize::Sm.as_slot_class("footer"), "");
+        assert_eq!(CardSize::SLOTS, ["header", "title"]);
+
+        #[derive(TwClass)]
+        #[tw(slot(name = "header"), slot(name = "title"))]
+        struct Panel<T: AsTailwindClass + AsTailwindSlotClass> {
+            size: T,
+        }
+
+        let panel = Panel {
+            size: CardSize::Default,
+        };
+        assert_eq!(panel.slots().title(""), "text-lg");
+    }
+
     #[test]
     fn variant_join() {
         assert_eq!(
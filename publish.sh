@@ -6,6 +6,9 @@ if [ $? -ne 0 ]; then
     exit 1
 fi
 
+echo "Publishing core"
+cargo publish --manifest-path ./core/Cargo.toml $@
+
 echo "Publishing variant-macro"
 cargo publish --manifest-path ./variant-macro/Cargo.toml $@
 
@@ -3,7 +3,9 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::DeriveInput;
 
-use crate::model::{TwClassContainer, TwClassField};
+use crate::generics::{builder_bound, merge_where, where_with_bounds};
+use crate::model::{TwClassContainer, TwClassField, TwCompound};
+use crate::strict::Strict;
 
 pub fn class_impl(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
@@ -16,12 +18,49 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
 
     let struct_ident = &container.ident;
     let builder_ident = format_ident!("{struct_ident}Builder");
+    let generics = &container.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let fields = container
         .data
         .take_struct()
         .expect("Expected struct fields");
 
+    if let Some(field) = fields
+        .iter()
+        .find(|field| !field.is_bool() && is_bool_type(&field.ty))
+    {
+        let error = syn::Error::new_spanned(
+            &field.ty,
+            "`bool` fields need `#[tw(when_true = \"...\")]` or `#[tw(when_false = \"...\")]`",
+        );
+        return TokenStream::from(error.to_compile_error());
+    }
+
+    if container.signals.is_present() && !cfg!(feature = "leptos") {
+        let error = syn::Error::new(
+            container.signals.span(),
+            "`#[tw(signals)]` needs the `leptos` feature of `tailwind_fuse`",
+        );
+        return TokenStream::from(error.to_compile_error());
+    }
+
+    // The field classes depend on the field values, so only a `TwVariant` can be merged when compiling.
+    if container.precompute.is_present() {
+        let error = syn::Error::new(
+            container.precompute.span(),
+            "`precompute` is only supported on `TwVariant`, a `TwClass` merges its fields at runtime",
+        );
+        return TokenStream::from(error.to_compile_error());
+    }
+
+    let strict = match container.strict.map(Strict::new).transpose() {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
     let base_class = container
         .class
         .as_ref()
@@ -37,15 +76,36 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
         }
     };
 
+    // Builders are `Copy` unless `no_copy` is set, for fields like variants with data.
+    let (builder_derive, builder_copy_bound, builder_copy) = if container.no_copy.is_present() {
+        (quote! { Clone }, quote! { Clone }, quote! { self.clone() })
+    } else {
+        (quote! { Copy, Clone }, quote! { Copy }, quote! { (*self) })
+    };
+
     let builder_struct = {
         let builder_fields = fields.iter().map(|field| {
             let TwClassField { ident, ty, .. } = field;
-            quote! { #ident: Option<#ty> }
+            // Bounded per field, so types that can't be deserialized only fail when used.
+            let serde = cfg!(feature = "serde").then(|| {
+                let bound = quote! { #ty: integrations::serde::Deserialize<'de> }.to_string();
+                quote! { #[serde(bound(deserialize = #bound))] }
+            });
+            quote! { #serde #ident: Option<#ty> }
+        });
+
+        // Missing fields fall back to the defaults when building.
+        let serde = cfg!(feature = "serde").then(|| {
+            quote! {
+                #[derive(integrations::serde::Deserialize)]
+                #[serde(crate = "integrations::serde")]
+            }
         });
 
         quote! {
-            #[derive(Copy, Clone, Default)]
-            pub struct #builder_ident {
+            #[derive(#builder_derive)]
+            #serde
+            pub struct #builder_ident #generics #where_clause {
                 #(#builder_fields,)*
             }
         }
@@ -56,6 +116,61 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
         .map(|field| field.ident.as_ref().expect("struct field has ident"))
         .collect::<Vec<_>>();
 
+    // Derived `Default` would require every type parameter to be `Default`.
+    let builder_default = quote! {
+        impl #impl_generics Default for #builder_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                #builder_ident {
+                    #(#field_idents: None,)*
+                }
+            }
+        }
+    };
+
+    let build_where = where_with_bounds(
+        generics,
+        fields
+            .iter()
+            .filter(|field| field.default.is_none())
+            .map(|field| &field.ty),
+        quote! { Default },
+    );
+    let class_where = where_with_bounds(
+        generics,
+        fields
+            .iter()
+            .filter(|field| !field.is_bool())
+            .map(|field| &field.ty),
+        quote! { AsTailwindClassCow },
+    );
+    let builder_class_where = merge_where(&[
+        &build_where,
+        &class_where,
+        &builder_bound(generics, &builder_ident, builder_copy_bound.clone()),
+    ]);
+
+    let builder_fallbacks = match fields
+        .iter()
+        .map(field_default)
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
+    // `#[derive(Default)]` would ignore the field defaults, so implement it here instead.
+    let default_impl = fields.iter().any(|field| field.default.is_some()).then(|| {
+        quote! {
+            impl #impl_generics Default for #struct_ident #ty_generics #build_where {
+                fn default() -> Self {
+                    #builder_ident::default().build()
+                }
+            }
+        }
+    });
+
     let builder_impl = {
         let builder_set_methods = fields.iter().map(|field| {
             let TwClassField { ident, ty, .. } = field;
@@ -68,12 +183,12 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
         });
 
         quote! {
-            impl #builder_ident {
+            impl #impl_generics #builder_ident #ty_generics #build_where {
                 #(#builder_set_methods)*
 
-                pub fn build(self) -> #struct_ident {
+                pub fn build(self) -> #struct_ident #ty_generics {
                     #struct_ident {
-                        #(#field_idents: self.#field_idents.unwrap_or_default(),)*
+                        #(#field_idents: self.#field_idents #builder_fallbacks,)*
                     }
                 }
             }
@@ -82,36 +197,68 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
 
     let builder_to_tailwind = {
         quote! {
-            impl IntoTailwindClass for #builder_ident {
+            impl #impl_generics IntoTailwindClass for #builder_ident #ty_generics #builder_class_where {
                 fn to_class(&self) -> String {
                     self.with_class("")
                 }
 
                 fn with_class(&self, class: impl AsRef<str>) -> String {
-                    (*self).build().with_class(class)
+                    #builder_copy.build().with_class(class)
                 }
             }
         }
     };
 
+    let compound_refs = match container
+        .compounds
+        .iter()
+        .map(|compound| compound_class(compound, &fields.fields))
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
     let struct_to_tailwind = {
-        let field_refs = fields.iter().map(|field| {
+        // Bound first, so borrowed classes outlive the array below.
+        let field_bindings = fields.iter().filter(|field| !field.is_bool()).map(|field| {
             let field_name = &field.ident;
+            let binding = class_binding(field);
             quote! {
-                self.#field_name.as_class(),
+                let #binding = self.#field_name.as_class_cow();
+            }
+        });
+        let field_refs = fields.iter().map(|field| {
+            let field_name = &field.ident;
+            if field.is_bool() {
+                let empty = syn::LitStr::new("", proc_macro2::Span::call_site());
+                let when_true = field.when_true.as_ref().unwrap_or(&empty);
+                let when_false = field.when_false.as_ref().unwrap_or(&empty);
+                quote! {
+                    if self.#field_name { #when_true } else { #when_false },
+                }
+            } else {
+                let binding = class_binding(field);
+                quote! {
+                    &*#binding,
+                }
             }
         });
 
         quote! {
-            impl IntoTailwindClass for #struct_ident {
+            impl #impl_generics IntoTailwindClass for #struct_ident #ty_generics #class_where {
                 fn to_class(&self) -> String {
                     self.with_class("")
                 }
 
                 fn with_class(&self, class: impl AsRef<str>) -> String {
+                    #( #field_bindings )*
                     let classes = [
                         #base_class,
                         #( #field_refs )*
+                        #( #compound_refs )*
                         class.as_ref(),
                     ];
                     #merger.fuse_classes(&classes)
@@ -120,13 +267,208 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
         }
     };
 
+    let slots = (!container.slots.is_empty()).then(|| {
+        let slots_ident = format_ident!("{struct_ident}Slots");
+        let slot_names = container
+            .slots
+            .iter()
+            .map(|slot| &slot.name)
+            .collect::<Vec<_>>();
+        let slot_strings = slot_names.iter().map(|name| name.to_string());
+        let slot_classes = container.slots.iter().map(|slot| {
+            let name = slot.name.to_string();
+            let class = slot
+                .class
+                .as_ref()
+                .map(syn::LitStr::value)
+                .unwrap_or_default();
+            let field_names = fields
+                .iter()
+                .filter(|field| !field.is_bool())
+                .map(|field| &field.ident);
+            quote! {
+                #merger.fuse_classes(&[
+                    #class,
+                    #( self.#field_names.as_slot_class(#name), )*
+                ])
+            }
+        });
+        let slot_docs = slot_strings
+            .clone()
+            .map(|name| format!("The class of the `{name}` slot, with the override class."));
+        // A slot that a field adds a class to but isn't declared here is likely a typo.
+        let slot_checks = fields
+            .iter()
+            .filter(|field| !field.is_bool())
+            .map(|field| {
+                let ty = &field.ty;
+                let declared = slot_strings.clone();
+                let error = format!(
+                    "`{}` has a slot that isn't declared on `{struct_ident}`, add it with `#[tw(slot(name = \"...\"))]`",
+                    field.ident.as_ref().map(ToString::to_string).unwrap_or_default(),
+                );
+                quote! {
+                    assert!(
+                        slots_declared(<#ty as AsTailwindSlotClass>::SLOTS, &[ #( #declared ),* ]),
+                        #error
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+        // Generic fields are only checked once `slots` is used with concrete types.
+        let (module_checks, slot_checks) = if generics.params.is_empty() {
+            (quote! { #( const _: () = #slot_checks; )* }, quote! {})
+        } else {
+            (quote! {}, quote! { #( const { #slot_checks }; )* })
+        };
+
+        let slots_where = where_with_bounds(
+            generics,
+            fields
+                .iter()
+                .filter(|field| !field.is_bool())
+                .map(|field| &field.ty),
+            quote! { AsTailwindSlotClass },
+        );
+        let builder_slots_where = merge_where(&[
+            &build_where,
+            &slots_where,
+            &builder_bound(generics, &builder_ident, builder_copy_bound.clone()),
+        ]);
+
+        quote! {
+            #module_checks
+
+            /// The class of each slot, see `slots`.
+            pub struct #slots_ident {
+                #( #slot_names: String, )*
+            }
+
+            impl #slots_ident {
+                #(
+                    #[doc = #slot_docs]
+                    pub fn #slot_names(&self, class: impl AsRef<str>) -> String {
+                        #merger.fuse_classes(&[&self.#slot_names, class.as_ref()])
+                    }
+                )*
+            }
+
+            impl #impl_generics #struct_ident #ty_generics #slots_where {
+                /// The classes of the slots, for the parts of the component.
+                pub fn slots(&self) -> #slots_ident {
+                    #slot_checks
+                    #slots_ident {
+                        #( #slot_names: #slot_classes, )*
+                    }
+                }
+            }
+
+            impl #impl_generics #builder_ident #ty_generics #builder_slots_where {
+                /// The classes of the slots, for the parts of the component.
+                pub fn slots(&self) -> #slots_ident {
+                    #builder_copy.build().slots()
+                }
+            }
+        }
+    });
+
+    let signals = container.signals.is_present().then(|| {
+        let signals_ident = format_ident!("{struct_ident}Signals");
+        let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+        let signals_where = merge_where(&[
+            &build_where,
+            &class_where,
+            &where_with_bounds(
+                generics,
+                field_types.iter().copied(),
+                quote! { Clone + Send + Sync + 'static },
+            ),
+        ]);
+
+        quote! {
+            /// A Leptos signal for each field, see `with_class`.
+            #[derive(Copy, Clone)]
+            pub struct #signals_ident #generics #where_clause {
+                #( pub #field_idents: signal::Signal<#field_types>, )*
+            }
+
+            impl #impl_generics #signals_ident #ty_generics #signals_where {
+                /// A memoised signal of the class, with the override class.
+                pub fn with_class(self, class: impl signal::IntoClassSignal) -> signal::Signal<String> {
+                    let class = signal::IntoClassSignal::into_class_signal(class);
+                    signal::memo_class(move || {
+                        let value = #struct_ident {
+                            #( #field_idents: signal::Get::get(&self.#field_idents), )*
+                        };
+                        value.with_class(signal::Get::get(&class))
+                    })
+                }
+
+                /// A memoised signal of the class.
+                pub fn to_class(self) -> signal::Signal<String> {
+                    self.with_class("")
+                }
+            }
+
+            impl #impl_generics Default for #signals_ident #ty_generics #signals_where {
+                fn default() -> Self {
+                    #struct_ident::from(#builder_ident::default()).into()
+                }
+            }
+
+            impl #impl_generics From<#struct_ident #ty_generics> for #signals_ident #ty_generics #signals_where {
+                fn from(value: #struct_ident #ty_generics) -> Self {
+                    #signals_ident {
+                        #( #field_idents: signal::Signal::stored(value.#field_idents), )*
+                    }
+                }
+            }
+        }
+    });
+
+    let integrations = integrations(
+        &quote! { impl #impl_generics },
+        &quote! { #struct_ident #ty_generics },
+        &class_where,
+    )
+    .chain(integrations(
+        &quote! { impl #impl_generics },
+        &quote! { #builder_ident #ty_generics },
+        &builder_class_where,
+    ));
+
+    let validation = strict.map(|strict| {
+        let field_classes = fields
+            .iter()
+            .flat_map(|field| field.when_true.iter().chain(&field.when_false));
+        let compound_classes = container.compounds.iter().map(|c| &c.class);
+        let slot_classes = container
+            .slots
+            .iter()
+            .filter_map(|slot| slot.class.as_ref());
+        container
+            .class
+            .iter()
+            .chain(field_classes)
+            .chain(compound_classes)
+            .chain(slot_classes)
+            .map(|class| strict.validate_class(class))
+            .collect::<proc_macro2::TokenStream>()
+    });
+
     let gen = quote! {
+        #validation
+
         #builder_struct
 
+        #builder_default
+
         #builder_impl
 
-        impl IntoBuilder for #struct_ident {
-            type Builder = #builder_ident;
+        #default_impl
+
+        impl #impl_generics IntoBuilder for #struct_ident #ty_generics #where_clause {
+            type Builder = #builder_ident #ty_generics;
 
             fn builder() -> Self::Builder {
                 Default::default()
@@ -136,16 +478,16 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl From<#struct_ident> for #builder_ident {
-            fn from(value: #struct_ident) -> Self {
+        impl #impl_generics From<#struct_ident #ty_generics> for #builder_ident #ty_generics #where_clause {
+            fn from(value: #struct_ident #ty_generics) -> Self {
                 #builder_ident {
                     #(#field_idents: Some(value.#field_idents),)*
                 }
             }
         }
 
-        impl From<#builder_ident> for #struct_ident {
-            fn from(value: #builder_ident) -> Self {
+        impl #impl_generics From<#builder_ident #ty_generics> for #struct_ident #ty_generics #build_where {
+            fn from(value: #builder_ident #ty_generics) -> Self {
                 value.build()
             }
         }
@@ -153,7 +495,163 @@ pub fn class_impl(input: TokenStream) -> TokenStream {
         #builder_to_tailwind
 
         #struct_to_tailwind
+
+        #slots
+
+        #signals
+
+        #( #integrations )*
     };
 
     gen.into()
 }
+
+// `if matches!(self.size, <Size>::Sm | <Size>::Md) && ... { "class" } else { "" },`
+// Option fields match `Some(<Size>::Sm)`, and bool fields match `true` or `false`.
+fn compound_class(
+    compound: &TwCompound,
+    fields: &[TwClassField],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let conditions = compound
+        .conditions
+        .iter()
+        .map(|(name, variants)| {
+            let field = fields
+                .iter()
+                .find(|field| field.ident.as_ref() == Some(name))
+                .ok_or_else(|| syn::Error::new(name.span(), format!("Unknown field `{name}`")))?;
+            if is_bool_type(&field.ty) {
+                let mut values = variants
+                    .iter()
+                    .map(|variant| match variant.to_string().as_str() {
+                        "true" => Ok(true),
+                        "false" => Ok(false),
+                        _ => Err(syn::Error::new(
+                            variant.span(),
+                            "Expected `true` or `false`",
+                        )),
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                values.dedup();
+                return Ok(match values.as_slice() {
+                    [true] => quote! { self.#name },
+                    [false] => quote! { !self.#name },
+                    _ => quote! { true },
+                });
+            }
+            let patterns = match option_type(&field.ty) {
+                Some(ty) => variants
+                    .iter()
+                    .map(|variant| quote! { Some(<#ty>::#variant) })
+                    .collect::<Vec<_>>(),
+                None => {
+                    let ty = &field.ty;
+                    variants
+                        .iter()
+                        .map(|variant| quote! { <#ty>::#variant })
+                        .collect()
+                }
+            };
+            Ok(quote! { matches!(self.#name, #( #patterns )|*) })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let class = &compound.class;
+
+    Ok(quote! {
+        if #( #conditions )&&* { #class } else { "" },
+    })
+}
+
+fn is_bool_type(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.path.is_ident("bool"))
+}
+
+// `.unwrap_or_default()`, or `.unwrap_or(<Size>::Sm)` with `#[tw(default = "Sm")]`.
+fn field_default(field: &TwClassField) -> syn::Result<proc_macro2::TokenStream> {
+    let Some(default) = &field.default else {
+        return Ok(quote! { .unwrap_or_default() });
+    };
+
+    let value = if is_bool_type(&field.ty) {
+        let value = default
+            .parse::<syn::LitBool>()
+            .map_err(|_| syn::Error::new(default.span(), "Expected `true` or `false`"))?;
+        quote! { #value }
+    } else {
+        let variant = default
+            .parse::<syn::Ident>()
+            .map_err(|_| syn::Error::new(default.span(), "Expected an enum variant, like `Sm`"))?;
+        match option_type(&field.ty) {
+            Some(ty) => quote! { Some(<#ty>::#variant) },
+            None => {
+                let ty = &field.ty;
+                quote! { <#ty>::#variant }
+            }
+        }
+    };
+
+    Ok(quote! { .unwrap_or(#value) })
+}
+
+// `Option<Size>` -> `Size`
+fn option_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+// `size` -> `size_class`
+fn class_binding(field: &TwClassField) -> syn::Ident {
+    format_ident!(
+        "{}_class",
+        field.ident.as_ref().expect("struct field has ident")
+    )
+}
+
+// Framework conversions for a type that implements `IntoTailwindClass`.
+// Empty without the `dioxus` and `yew` features.
+#[allow(unused_mut, unused_variables)]
+fn integrations(
+    impl_generics: &proc_macro2::TokenStream,
+    ty: &proc_macro2::TokenStream,
+    where_clause: &syn::WhereClause,
+) -> impl Iterator<Item = proc_macro2::TokenStream> {
+    let mut integrations = vec![];
+
+    #[cfg(feature = "dioxus")]
+    integrations.push(quote! {
+        #impl_generics integrations::dioxus::IntoAttributeValue for #ty #where_clause {
+            fn into_value(self) -> integrations::dioxus::AttributeValue {
+                integrations::dioxus::AttributeValue::Text(self.to_class())
+            }
+        }
+    });
+
+    #[cfg(feature = "yew")]
+    integrations.push(quote! {
+        #impl_generics From<#ty> for integrations::yew::Classes #where_clause {
+            fn from(value: #ty) -> Self {
+                value.to_class().into()
+            }
+        }
+
+        #impl_generics From<#ty> for integrations::yew::AttrValue #where_clause {
+            fn from(value: #ty) -> Self {
+                value.to_class().into()
+            }
+        }
+    });
+
+    integrations.into_iter()
+}
@@ -0,0 +1,67 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// The where clause of `generics`, plus `#ty: #bound` for each type that uses a type parameter.
+///
+/// Concrete field types are left alone, so non-generic structs expand as before.
+pub fn where_with_bounds<'a>(
+    generics: &syn::Generics,
+    types: impl IntoIterator<Item = &'a syn::Type>,
+    bound: TokenStream,
+) -> syn::WhereClause {
+    let mut where_clause = generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| syn::parse_quote!(where));
+
+    for ty in types {
+        if uses_type_param(generics, ty) {
+            where_clause.predicates.push(syn::parse_quote!(#ty: #bound));
+        }
+    }
+
+    where_clause
+}
+
+/// Merges the predicates of several where clauses.
+pub fn merge_where(clauses: &[&syn::WhereClause]) -> syn::WhereClause {
+    let mut where_clause: syn::WhereClause = syn::parse_quote!(where);
+    for clause in clauses {
+        for predicate in &clause.predicates {
+            if !where_clause.predicates.iter().any(|p| p == predicate) {
+                where_clause.predicates.push(predicate.clone());
+            }
+        }
+    }
+    where_clause
+}
+
+/// `Builder<T>: #bound`, needed to build from `&self` when the builder is only conditionally `Copy` or `Clone`.
+pub fn builder_bound(
+    generics: &syn::Generics,
+    builder: &syn::Ident,
+    bound: TokenStream,
+) -> syn::WhereClause {
+    if generics.type_params().next().is_some() {
+        let (_, ty_generics, _) = generics.split_for_impl();
+        syn::parse_quote!(where #builder #ty_generics: #bound)
+    } else {
+        syn::parse_quote!(where)
+    }
+}
+
+fn uses_type_param(generics: &syn::Generics, ty: &syn::Type) -> bool {
+    fn contains(tokens: TokenStream, params: &[&syn::Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => params.contains(&&ident),
+            TokenTree::Group(group) => contains(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    let params = generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect::<Vec<_>>();
+    !params.is_empty() && contains(ty.to_token_stream(), &params)
+}
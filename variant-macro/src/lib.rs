@@ -3,10 +3,14 @@ use proc_macro::TokenStream;
 use class_macro::class_impl;
 use variant_macro::variant_impl;
 
-use crate::theme::{theme_class_impl, theme_variant_impl};
+use crate::theme::{theme_class_impl, theme_impl, theme_variant_impl};
 
 mod class_macro;
+mod generics;
 mod model;
+mod precompute;
+mod strict;
+mod template;
 mod theme;
 mod variant_macro;
 
@@ -29,3 +33,8 @@ pub fn theme_class(input: TokenStream) -> TokenStream {
 pub fn theme_variant(input: TokenStream) -> TokenStream {
     theme_variant_impl(input)
 }
+
+#[proc_macro_derive(TwTheme, attributes(tw))]
+pub fn theme(input: TokenStream) -> TokenStream {
+    theme_impl(input)
+}
@@ -1,34 +1,154 @@
 use darling::{
-    ast,
-    util::{Flag, IdentString},
-    FromDeriveInput, FromField, FromVariant,
+    ast::{self, NestedMeta},
+    util::{Flag, IdentString, Override},
+    FromDeriveInput, FromField, FromMeta, FromVariant,
 };
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(tw), supports(enum_unit))]
+#[darling(attributes(tw), supports(enum_any))]
 pub struct TwVariantContainer {
     pub ident: syn::Ident,
+    pub generics: syn::Generics,
     pub data: ast::Data<TwVariantOption, ()>,
     /// The base Tailwind class for the variant.
     pub class: Option<syn::LitStr>,
+    /// Validate the classes at compile time.
+    pub strict: Option<Override<TwStrict>>,
+    /// Merge the classes at compile time.
+    pub precompute: Flag,
 }
 
 #[derive(Debug, FromVariant)]
-#[darling(supports(unit), attributes(tw, default))]
+#[darling(attributes(tw, default))]
 pub struct TwVariantOption {
     pub ident: syn::Ident,
-    pub class: String,
+    /// Empty for unit variants, which have a static class.
+    pub fields: ast::Fields<syn::Field>,
+    /// A format template (e.g. `bg-[{0}]`) for variants with fields.
+    pub class: syn::LitStr,
     pub default: Flag,
+    /// Name used by `FromStr`, `Display` and serde, instead of the snake case variant name.
+    pub rename: Option<syn::LitStr>,
+    /// Classes for the slots of a `TwClass`.
+    #[darling(multiple, rename = "slot")]
+    pub slots: Vec<TwSlot>,
 }
 
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(tw), supports(struct_named))]
 pub struct TwClassContainer {
     pub ident: syn::Ident,
+    pub generics: syn::Generics,
     pub data: ast::Data<(), TwClassField>,
     pub class: Option<syn::LitStr>,
     /// Defaults to using `tw_merge`.
     pub merger: Option<IdentString>,
+    /// Validate the classes at compile time.
+    pub strict: Option<Override<TwStrict>>,
+    /// Only supported on `TwVariant`, rejected with an error.
+    pub precompute: Flag,
+    /// Generate a `{Struct}Signals` struct, with a Leptos signal for each field.
+    pub signals: Flag,
+    /// Only derive `Clone` on the builder, for fields that aren't `Copy`.
+    pub no_copy: Flag,
+    /// Classes applied when all of the given fields match.
+    #[darling(multiple, rename = "compound")]
+    pub compounds: Vec<TwCompound>,
+    /// Named parts of the component, each with its own class.
+    #[darling(multiple, rename = "slot")]
+    pub slots: Vec<TwSlot>,
+}
+
+/// `#[tw(strict(prefix = "tw-", version = 4, colors))]`, or just `#[tw(strict)]`
+#[derive(Debug, Default, FromMeta)]
+pub struct TwStrict {
+    /// The prefix of the classes, like the `prefix` of the merge config.
+    pub prefix: Option<syn::LitStr>,
+    /// The separator of the modifiers, defaults to `:`.
+    pub separator: Option<syn::LitStr>,
+    /// The Tailwind major version, `3` (the default) or `4`.
+    pub version: Option<syn::LitInt>,
+    /// Warn about colors outside the default palette.
+    pub colors: Flag,
+}
+
+/// `#[tw(slot(name = "header", class = "..."))]`
+#[derive(Debug, FromMeta)]
+pub struct TwSlot {
+    pub name: syn::Ident,
+    pub class: Option<syn::LitStr>,
+}
+
+/// `#[tw(compound(size = "Sm", variant = "Outline | Ghost", disabled = "true", class = "..."))]`
+#[derive(Debug)]
+pub struct TwCompound {
+    /// Field name, and the enum variants that match it.
+    pub conditions: Vec<(syn::Ident, Vec<syn::Ident>)>,
+    pub class: syn::LitStr,
+}
+
+impl FromMeta for TwCompound {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+        let mut conditions = vec![];
+        let mut class = None;
+
+        for item in items {
+            let NestedMeta::Meta(syn::Meta::NameValue(name_value)) = item else {
+                errors
+                    .push(darling::Error::custom("Expected `field = \"Variant\"`").with_span(item));
+                continue;
+            };
+            let Some(name) = name_value.path.get_ident() else {
+                errors.push(
+                    darling::Error::custom("Expected a field name").with_span(&name_value.path),
+                );
+                continue;
+            };
+            let Some(value) = errors.handle(syn::LitStr::from_expr(&name_value.value)) else {
+                continue;
+            };
+
+            if name == "class" {
+                class = Some(value);
+                continue;
+            }
+
+            let variants = value
+                .value()
+                .split('|')
+                .map(|variant| {
+                    // `parse_any` also accepts `true` and `false`, for bool fields.
+                    let mut ident: syn::Ident = syn::parse::Parser::parse_str(
+                        syn::ext::IdentExt::parse_any,
+                        variant.trim(),
+                    )?;
+                    ident.set_span(value.span());
+                    Ok(ident)
+                })
+                .collect::<syn::Result<Vec<_>>>()
+                .map_err(|_| {
+                    darling::Error::custom("Expected enum variants, like `Sm` or `Sm | Md`")
+                        .with_span(&value)
+                });
+            if let Some(variants) = errors.handle(variants) {
+                conditions.push((name.clone(), variants));
+            }
+        }
+
+        let class = errors.handle(class.ok_or_else(|| darling::Error::missing_field("class")));
+        if conditions.is_empty() {
+            errors.push(darling::Error::custom(
+                "Expected at least one field, like `size = \"Sm\"`",
+            ));
+        }
+        errors.finish()?;
+
+        Ok(Self {
+            conditions,
+            class: class.expect("missing class is reported as an error"),
+        })
+    }
 }
 
 #[derive(Debug, FromField)]
@@ -36,4 +156,16 @@ pub struct TwClassContainer {
 pub struct TwClassField {
     pub ty: syn::Type,
     pub ident: Option<syn::Ident>,
+    /// Class for a `bool` field that is `true`.
+    pub when_true: Option<syn::LitStr>,
+    /// Class for a `bool` field that is `false`.
+    pub when_false: Option<syn::LitStr>,
+    /// Builder fallback, instead of the variant's `#[tw(default)]`.
+    pub default: Option<syn::LitStr>,
+}
+
+impl TwClassField {
+    pub fn is_bool(&self) -> bool {
+        self.when_true.is_some() || self.when_false.is_some()
+    }
 }
@@ -0,0 +1,19 @@
+use tailwind_fuse_core::ast::AstParseOptions;
+use tailwind_fuse_core::get_collisions::get_collisions;
+use tailwind_fuse_core::merge::merge;
+use tailwind_fuse_core::MergeTheme;
+
+/// Merges the classes at expansion time, for `#[tw(precompute)]`.
+///
+/// Uses the default merge rules, so a custom prefix, theme or collision rules set at runtime are not applied.
+pub fn merge_classes(classes: &[&str]) -> String {
+    merge(
+        classes,
+        AstParseOptions::default(),
+        &MergeTheme::default(),
+        |_, _, _| None,
+        // A closure, so `Vec<&'static str>` can shorten to the lifetime of `classes`.
+        #[allow(clippy::redundant_closure)]
+        |collision_id| get_collisions(collision_id),
+    )
+}
@@ -0,0 +1,141 @@
+use darling::util::Override;
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use tailwind_fuse_core::ast::{parse_tailwind, AstParseOptions};
+use tailwind_fuse_core::colors::{is_color_collision_id, is_default_color};
+use tailwind_fuse_core::get_collision_id::color_with_opacity;
+use tailwind_fuse_core::merge::collision_id_table;
+use tailwind_fuse_core::{MergeTheme, TailwindVersion};
+
+use crate::model::TwStrict;
+
+/// The options of `#[tw(strict)]`, matching the merge config the classes are written for.
+pub struct Strict {
+    prefix: String,
+    separator: String,
+    version: TailwindVersion,
+    colors: bool,
+}
+
+impl Strict {
+    pub fn new(strict: Override<TwStrict>) -> syn::Result<Self> {
+        let strict = strict.unwrap_or_default();
+        let version = match &strict.version {
+            None => TailwindVersion::V3,
+            Some(version) => match version.base10_parse::<u8>()? {
+                3 => TailwindVersion::V3,
+                4 => TailwindVersion::V4,
+                _ => {
+                    return Err(syn::Error::new(
+                        version.span(),
+                        "Unsupported Tailwind version, expected `3` or `4`",
+                    ))
+                }
+            },
+        };
+        Ok(Self {
+            prefix: strict.prefix.map(|lit| lit.value()).unwrap_or_default(),
+            separator: strict
+                .separator
+                .map(|lit| lit.value())
+                .unwrap_or_else(|| ":".to_string()),
+            version,
+            colors: strict.colors.is_present(),
+        })
+    }
+
+    /// Validates the classes in a `#[tw(class = "...")]` literal.
+    ///
+    /// Classes that can't be parsed or classified are errors.
+    /// With `colors`, colors outside the default palette are warnings, because they may come from your Tailwind config.
+    pub fn validate_class(&self, lit: &syn::LitStr) -> TokenStream {
+        let value = lit.value();
+        let theme = MergeTheme::default();
+        let options = AstParseOptions {
+            prefix: &self.prefix,
+            separator: &self.separator,
+            version: self.version,
+        };
+        let get_collision_id = collision_id_table(self.version);
+
+        parse_tailwind(&[value.as_str()], options)
+            .into_iter()
+            .filter_map(|style| {
+                let (class, error) = match style {
+                    Err(class) => (class, format!("Failed to parse Tailwind class `{class}`")),
+                    Ok(style) => {
+                        let arbitrary = style.arbitrary.unwrap_or_default();
+                        let result = match color_with_opacity(&style.elements, style.postfix)
+                            .map(Ok)
+                            .unwrap_or_else(|| get_collision_id(&style.elements, arbitrary, &theme))
+                        {
+                            Err(_) if is_arbitrary_property(&style.elements, arbitrary) => {
+                                return None
+                            }
+                            Err(error) => match style.elements_with_postfix() {
+                                Some(elements) => get_collision_id(&elements, arbitrary, &theme)
+                                    .map_err(|_| error),
+                                None => Err(error),
+                            },
+                            result => result,
+                        };
+                        match result {
+                            Ok(collision_id)
+                                if self.colors
+                                    && is_color_collision_id(collision_id)
+                                    && style.arbitrary.is_none()
+                                    && !is_default_color(&style.elements) =>
+                            {
+                                let span = class_span(lit, &value, style.source);
+                                let note = format!(
+                                    "`{}` is not a default Tailwind color. Ignore this if it is in your Tailwind config.",
+                                    style.source
+                                );
+                                return Some(warning(span, &note));
+                            }
+                            Ok(_) => return None,
+                            Err(error) => (style.source, format!("{error}: `{}`", style.source)),
+                        }
+                    }
+                };
+                let span = class_span(lit, &value, class);
+                Some(syn::Error::new(span, error).to_compile_error())
+            })
+            .collect()
+    }
+}
+
+// `[mask-type:luminance]`
+fn is_arbitrary_property(elements: &[&str], arbitrary: &str) -> bool {
+    elements.is_empty() && arbitrary.contains(':')
+}
+
+// Points inside the string when the compiler supports it, otherwise at the whole literal.
+fn class_span(lit: &syn::LitStr, value: &str, class: &str) -> Span {
+    let token = lit.token();
+    let repr = token.to_string();
+    // Offsets only line up when the literal has no escapes.
+    if repr.contains('\\') {
+        return lit.span();
+    }
+    let Some(quote) = repr.find('"') else {
+        return lit.span();
+    };
+    let start = quote + 1 + (class.as_ptr() as usize - value.as_ptr() as usize);
+    token
+        .subspan(start..start + class.len())
+        .unwrap_or_else(|| lit.span())
+}
+
+// Stable proc macros can't emit warnings, so use a deprecated item at the span instead.
+fn warning(span: Span, note: &str) -> TokenStream {
+    let ident = syn::Ident::new("tailwind_class_warning", span);
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #ident: () = ();
+            #ident
+        };
+    }
+}
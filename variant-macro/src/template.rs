@@ -0,0 +1,116 @@
+use darling::ast::Fields;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+
+/// A class template for a variant with fields, like `bg-[{0}]` or `w-[{width}px]`.
+pub struct ClassTemplate {
+    /// The template, with positional arguments renamed to `_0`, `_1`, ... so they can be captured by `format!`.
+    pub format: String,
+    /// The fields the template uses.
+    pub members: Vec<syn::Member>,
+}
+
+impl ClassTemplate {
+    /// Parses `{0}`, `{}` and `{name}` arguments, keeping any format spec (`{0:>4}`).
+    pub fn parse(lit: &syn::LitStr, fields: &Fields<syn::Field>) -> syn::Result<Self> {
+        let value = lit.value();
+        let mut format = String::with_capacity(value.len());
+        let mut members = vec![];
+        let mut next_index = 0;
+
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    format.push_str("{{");
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    format.push_str("}}");
+                }
+                '{' => {
+                    let mut argument = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => argument.push(c),
+                            None => {
+                                return Err(syn::Error::new(lit.span(), "Unclosed `{` in class"))
+                            }
+                        }
+                    }
+                    let (name, spec) = match argument.split_once(':') {
+                        Some((name, spec)) => (name.trim(), Some(spec)),
+                        None => (argument.trim(), None),
+                    };
+                    let member = if name.is_empty() {
+                        next_index += 1;
+                        syn::Member::from(next_index - 1)
+                    } else if let Ok(index) = name.parse::<usize>() {
+                        syn::Member::from(index)
+                    } else {
+                        let message = format!("Invalid field `{name}` in class");
+                        syn::parse_str::<syn::Ident>(name)
+                            .map(syn::Member::Named)
+                            .map_err(|_| syn::Error::new(lit.span(), message))?
+                    };
+                    if !has_member(fields, &member) {
+                        let message = format!("No field `{}` in variant", name_of(&member));
+                        return Err(syn::Error::new(lit.span(), message));
+                    }
+
+                    format.push('{');
+                    format.push_str(&binding(&member).to_string());
+                    if let Some(spec) = spec {
+                        format.push(':');
+                        format.push_str(spec);
+                    }
+                    format.push('}');
+                    if !members.contains(&member) {
+                        members.push(member);
+                    }
+                }
+                '}' => return Err(syn::Error::new(lit.span(), "Unmatched `}` in class")),
+                c => format.push(c),
+            }
+        }
+
+        Ok(Self { format, members })
+    }
+
+    /// `{ 0: _0, width, .. }`, binding only the fields used by the template.
+    pub fn pattern(&self) -> TokenStream {
+        let fields = self.members.iter().map(|member| match member {
+            syn::Member::Named(name) => quote! { #name },
+            syn::Member::Unnamed(_) => {
+                let binding = binding(member);
+                quote! { #member: #binding }
+            }
+        });
+        quote! { { #( #fields, )* .. } }
+    }
+}
+
+fn has_member(fields: &Fields<syn::Field>, member: &syn::Member) -> bool {
+    match member {
+        syn::Member::Named(name) => fields
+            .iter()
+            .any(|field| field.ident.as_ref() == Some(name)),
+        syn::Member::Unnamed(index) => fields.is_tuple() && (index.index as usize) < fields.len(),
+    }
+}
+
+fn name_of(member: &syn::Member) -> String {
+    match member {
+        syn::Member::Named(name) => name.to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+fn binding(member: &syn::Member) -> syn::Ident {
+    match member {
+        syn::Member::Named(name) => syn::Ident::new(&name.to_string(), Span::call_site()),
+        syn::Member::Unnamed(index) => format_ident!("_{}", index.index),
+    }
+}
@@ -1,15 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
 use syn::LitStr;
 
 use model::TwTheme;
 use model::TwThemeClassContainer;
 pub use theme_class_macro::theme_class_impl;
+pub use theme_macro::theme_impl;
 pub use theme_variant_macro::theme_variant_impl;
 
 mod model;
 mod theme_class_macro;
+mod theme_macro;
 mod theme_variant_macro;
 
 fn construct_theme_container<T>(
@@ -24,9 +27,18 @@ where
         |mut container, item| {
             let value = item.class.as_ref().map(LitStr::value).unwrap_or_default();
             let key = match item.name {
+                Some(_) if item.prefix_with.is_some() => LitStr::new("base", Span::call_site()),
                 Some(name) => LitStr::new(&name.to_string(), name.span()),
                 None => LitStr::new("base", Span::call_site()),
             };
+            // Prefixed themes are part of the base theme, like `dark:bg-black` next to `bg-white`.
+            let value = match &item.prefix_with {
+                Some(prefix) => {
+                    let separator = item.separator.as_ref().map(LitStr::value);
+                    prefix_classes(&value, &prefix.value(), separator.as_deref().unwrap_or(":"))
+                }
+                None => value,
+            };
             let ident = Ident::new(
                 &format!(
                     "{}_{}",
@@ -44,3 +56,127 @@ where
         },
     )
 }
+
+/// The names of the themes with `prefix_with`, which select the base theme.
+fn theme_aliases(themes: &[TwTheme]) -> syn::Result<Vec<LitStr>> {
+    let mut aliases = vec![];
+    for theme in themes {
+        if let (Some(_), Some(extends)) = (&theme.prefix_with, &theme.extends) {
+            let message =
+                "A theme with `prefix_with` is part of the base theme and can't use `extends`";
+            return Err(syn::Error::new(extends.span(), message));
+        }
+        if let (None, Some(separator)) = (&theme.prefix_with, &theme.separator) {
+            return Err(syn::Error::new(
+                separator.span(),
+                "`separator` needs `prefix_with`",
+            ));
+        }
+        if let (Some(_), Some(name)) = (&theme.prefix_with, &theme.name) {
+            let alias = LitStr::new(&name.to_string(), name.span());
+            if !aliases.contains(&alias) {
+                aliases.push(alias);
+            }
+        }
+    }
+    Ok(aliases)
+}
+
+// `bg-black hover:bg-gray-900` -> `dark:bg-black dark:hover:bg-gray-900`
+fn prefix_classes(classes: &str, prefix: &str, separator: &str) -> String {
+    classes
+        .split_whitespace()
+        .map(|class| format!("{prefix}{separator}{class}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The theme each theme `extends`, by name.
+fn theme_parents(themes: &[TwTheme]) -> syn::Result<HashMap<String, Ident>> {
+    let mut parents = HashMap::<String, Ident>::new();
+    for theme in themes {
+        let Some(extends) = &theme.extends else {
+            continue;
+        };
+        let name = theme
+            .name
+            .as_ref()
+            .map_or_else(|| "base".to_owned(), Ident::to_string);
+        match parents.get(&name) {
+            Some(parent) if parent != extends => {
+                let message = format!("Theme `{name}` already extends `{parent}`");
+                return Err(syn::Error::new(extends.span(), message));
+            }
+            _ => {
+                parents.insert(name, extends.clone());
+            }
+        }
+    }
+    Ok(parents)
+}
+
+/// Checks that each theme `extends` `base` or a declared theme, so a typo like `extends = dakr` doesn't compile.
+///
+/// With `theme_enum`, the parents are checked against the enum instead, see [`validate_theme_names`].
+fn validate_extends<'a>(
+    themes: &[TwTheme],
+    declared: impl IntoIterator<Item = &'a TwTheme>,
+) -> syn::Result<()> {
+    let declared = declared
+        .into_iter()
+        .filter_map(|theme| theme.name.as_ref().map(Ident::to_string))
+        .collect::<HashSet<_>>();
+    for extends in themes.iter().filter_map(|theme| theme.extends.as_ref()) {
+        let parent = extends.to_string();
+        if parent != "base" && !declared.contains(&parent) {
+            let message = format!("Unknown theme `{parent}`, expected `base` or a declared theme");
+            return Err(syn::Error::new(extends.span(), message));
+        }
+    }
+    Ok(())
+}
+
+/// The themes `name` is built from, starting with the theme furthest up the chain and ending with `name`.
+fn theme_chain(parents: &HashMap<String, Ident>, name: &str) -> syn::Result<Vec<String>> {
+    let mut chain = vec![name.to_owned()];
+    while let Some(parent) = parents.get(&chain[chain.len() - 1]) {
+        let parent_name = parent.to_string();
+        if chain.contains(&parent_name) {
+            let message = format!("Theme `{name}` extends itself");
+            return Err(syn::Error::new(parent.span(), message));
+        }
+        chain.push(parent_name);
+    }
+    chain.reverse();
+    Ok(chain)
+}
+
+/// Checks that every theme name (other than `base`) is a variant of `theme_enum`, e.g. `dark` is `Theme::Dark`.
+fn validate_theme_names<'a>(
+    theme_enum: &syn::Path,
+    names: impl IntoIterator<Item = &'a LitStr>,
+) -> TokenStream {
+    let variants = names
+        .into_iter()
+        .filter(|name| name.value() != "base")
+        .map(|name| Ident::new(&to_pascal_case(&name.value()), name.span()));
+    quote! {
+        const _: () = {
+            #( let _ = #theme_enum::#variants; )*
+        };
+    }
+}
+
+// `high_contrast` -> `HighContrast`
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars))
+                .into_iter()
+                .flatten()
+        })
+        .collect()
+}
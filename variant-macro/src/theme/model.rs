@@ -1,5 +1,5 @@
-use darling::{ast, FromDeriveInput, FromField, FromMeta, FromVariant};
 use darling::util::{Flag, IdentString};
+use darling::{ast, FromDeriveInput, FromField, FromMeta, FromVariant};
 
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(tw), supports(enum_unit))]
@@ -8,6 +8,10 @@ pub struct TwThemeVariantContainer {
     pub data: ast::Data<TwThemeVariantOption, ()>,
     #[darling(multiple)]
     pub theme: Vec<TwTheme>,
+    /// Merges the classes of an `extends` chain. Defaults to using `tw_merge`.
+    pub merger: Option<IdentString>,
+    /// A `TwTheme` enum that every theme name must be a variant of.
+    pub theme_enum: Option<syn::Path>,
 }
 
 #[derive(Debug, FromVariant)]
@@ -25,17 +29,27 @@ pub struct TwTheme {
     /// then this class means that all subject items are shared.
     pub name: Option<syn::Ident>,
     pub class: Option<syn::LitStr>,
+    /// The theme this theme builds on, whose classes come first and fill in missing classes.
+    pub extends: Option<syn::Ident>,
+    /// A Tailwind variant (e.g. `dark`) to prefix every class with, adding the classes to the base theme.
+    pub prefix_with: Option<syn::LitStr>,
+    /// The separator after `prefix_with`, defaults to `:`.
+    pub separator: Option<syn::LitStr>,
 }
 
 #[derive(Debug, FromDeriveInput)]
 #[darling(attributes(tw), supports(struct_named))]
 pub struct TwThemeClassContainer {
     pub ident: syn::Ident,
+    pub vis: syn::Visibility,
+    pub generics: syn::Generics,
     pub data: ast::Data<(), TwThemeClassField>,
     #[darling(multiple)]
     pub theme: Vec<TwTheme>,
     /// Defaults to using `tw_merge`.
     pub merger: Option<IdentString>,
+    /// A `TwTheme` enum that every theme name must be a variant of.
+    pub theme_enum: Option<syn::Path>,
 }
 
 #[derive(Debug, FromField)]
@@ -44,3 +58,16 @@ pub struct TwThemeClassField {
     pub ty: syn::Type,
     pub ident: Option<syn::Ident>,
 }
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(tw), supports(enum_unit))]
+pub struct TwThemeContainer {
+    pub ident: syn::Ident,
+    pub data: ast::Data<TwThemeOption, ()>,
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(tw))]
+pub struct TwThemeOption {
+    pub ident: syn::Ident,
+}
@@ -1,11 +1,16 @@
 use proc_macro::TokenStream;
 
 use darling::FromDeriveInput;
+use proc_macro2::Span;
 use quote::{format_ident, quote};
-use syn::DeriveInput;
+use syn::{DeriveInput, LitStr};
 
-use crate::theme::{construct_theme_container, TwThemeClassContainer};
+use crate::generics::{builder_bound, merge_where, where_with_bounds};
 use crate::theme::model::TwThemeClassField;
+use crate::theme::{
+    construct_theme_container, theme_aliases, theme_chain, theme_parents, validate_extends,
+    validate_theme_names, TwThemeClassContainer,
+};
 
 pub fn theme_class_impl(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
@@ -19,14 +24,72 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
     let struct_ident = &container.ident;
 
     let builder_ident = format_ident!("{struct_ident}Builder");
+    let generics = &container.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let fields = container
         .data
         .take_struct()
         .expect("Expected struct fields");
 
+    let parents = match theme_parents(&container.theme) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
+    if container.theme_enum.is_none() {
+        if let Err(e) = validate_extends(&container.theme, &container.theme) {
+            return TokenStream::from(e.to_compile_error());
+        }
+    }
+
+    let aliases = match theme_aliases(&container.theme) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
+    // Themes with `prefix_with` are part of the base theme.
+    let alias_match = (!aliases.is_empty()).then(|| {
+        quote! {
+            let name = match name {
+                #( #aliases )|* => "base",
+                name => name,
+            };
+        }
+    });
+
+    // Each theme that extends another is resolved from the start of its chain.
+    let chain_cases = match parents
+        .keys()
+        .map(|name| {
+            let chain = theme_chain(&parents, name)?;
+            Ok(quote! {
+                #name => &[ #( #chain ),* ],
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
     let theme_container = construct_theme_container(container.theme, struct_ident.to_string());
 
+    let validation = container.theme_enum.as_ref().map(|theme_enum| {
+        let extended = parents
+            .values()
+            .map(|parent| LitStr::new(&parent.to_string(), parent.span()))
+            .collect::<Vec<_>>();
+        let names = theme_container.keys().chain(&extended).chain(&aliases);
+        validate_theme_names(theme_enum, names)
+    });
+
     let constant_variables = theme_container.iter().map(|(_name, (class, ident))| {
         quote! {
             const #ident: &'static str = #class;
@@ -39,6 +102,12 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
         }
     });
 
+    // With `theme_enum`, only its variants select a theme.
+    let theme_type = match &container.theme_enum {
+        Some(theme_enum) => quote! { #theme_enum },
+        None => quote! { str },
+    };
+
     let merger = {
         if let Some(merger) = container.merger {
             let ident = merger.as_ident();
@@ -53,9 +122,11 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
             let TwThemeClassField { ident, ty, .. } = field;
             quote! { #ident: Option<#ty> }
         });
+        // The builder's `Theme` is the `theme_enum`, so it can't be more public than the struct.
+        let vis = &container.vis;
         quote! {
-            #[derive(Copy, Clone, Default)]
-            pub struct #builder_ident {
+            #[derive(Copy, Clone)]
+            #vis struct #builder_ident #generics #where_clause {
                 #(#builder_fields,)*
             }
         }
@@ -66,6 +137,30 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
         .map(|field| field.ident.as_ref().expect("struct field has ident"))
         .collect::<Vec<_>>();
 
+    // Derived `Default` would require every type parameter to be `Default`.
+    let builder_default = quote! {
+        impl #impl_generics Default for #builder_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                #builder_ident {
+                    #(#field_idents: None,)*
+                }
+            }
+        }
+    };
+
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let build_where = where_with_bounds(generics, field_types.iter().copied(), quote! { Default });
+    let class_where = where_with_bounds(
+        generics,
+        field_types.iter().copied(),
+        quote! { AsTailwindThemeClass },
+    );
+    let builder_class_where = merge_where(&[
+        &build_where,
+        &class_where,
+        &builder_bound(generics, &builder_ident, quote! { Copy }),
+    ]);
+
     let builder_impl = {
         let builder_set_methods = fields.iter().map(|field| {
             let TwThemeClassField { ident, ty, .. } = field;
@@ -78,10 +173,10 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
         });
 
         quote! {
-            impl #builder_ident {
+            impl #impl_generics #builder_ident #ty_generics #build_where {
                 #(#builder_set_methods)*
 
-                pub fn build(self) -> #struct_ident {
+                pub fn build(self) -> #struct_ident #ty_generics {
                     #struct_ident {
                         #(#field_idents: self.#field_idents.unwrap_or_default(),)*
                     }
@@ -92,46 +187,60 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
 
     let builder_to_tailwind = {
         quote! {
-            impl IntoTailwindThemeClass for #builder_ident {
-                fn to_class(&self, theme: Option<impl AsRef<str>>) -> String {
+            impl #impl_generics IntoTailwindThemeClass for #builder_ident #ty_generics #builder_class_where {
+                type Theme = #theme_type;
+
+                fn to_class(&self, theme: Option<impl AsTailwindTheme<Self::Theme>>) -> String {
                     self.with_class(theme, "")
                 }
 
-                fn with_class(&self, theme: Option<impl AsRef<str>>, class: impl AsRef<str>) -> String {
+                fn with_class(&self, theme: Option<impl AsTailwindTheme<Self::Theme>>, class: impl AsRef<str>) -> String {
                     (*self).build().with_class(theme, class)
                 }
             }
         }
     };
 
+    // Other themes may be registered at runtime, falling back to the base class.
+    let struct_name = struct_ident.to_string();
+    let fallback = match theme_container.get(&LitStr::new("base", Span::call_site())) {
+        Some((_, base)) => quote! { #base },
+        None => quote! { "" },
+    };
+
     let struct_to_tailwind = {
         let field_refs = fields.iter().map(|field| {
             let field_name = &field.ident;
             quote! {
-                self.#field_name.as_class(name),
+                classes.extend(themes.iter().map(|theme| self.#field_name.as_theme_class(theme)));
             }
         });
 
         quote! {
-            impl IntoTailwindThemeClass for #struct_ident {
-                fn to_class(&self, theme: Option<impl AsRef<str>>) -> String {
+            impl #impl_generics IntoTailwindThemeClass for #struct_ident #ty_generics #class_where {
+                type Theme = #theme_type;
+
+                fn to_class(&self, theme: Option<impl AsTailwindTheme<Self::Theme>>) -> String {
                     self.with_class(theme, "")
                 }
 
-                fn with_class(&self, theme: Option<impl AsRef<str>>, class: impl AsRef<str>) -> String {
+                fn with_class(&self, theme: Option<impl AsTailwindTheme<Self::Theme>>, class: impl AsRef<str>) -> String {
                     let name = match theme {
-                        Some(ref theme) => theme.as_ref(),
+                        Some(ref theme) => theme.as_theme().theme_name(),
                         None => "base",
                     };
-                    let theme_class = match name.as_ref() {
-                        #( #to_class_cases )*
-                        _ => "",
+                    #alias_match
+                    let themes: &[&str] = match name {
+                        #( #chain_cases )*
+                        _ => std::slice::from_ref(&name),
                     };
-                    let classes = [
-                        theme_class,
-                        #( #field_refs )*
-                        class.as_ref(),
-                    ];
+                    let mut classes = Vec::new();
+                    classes.extend(themes.iter().map(|theme| match *theme {
+                        #( #to_class_cases )*
+                        theme => registry::class(theme, concat!(module_path!(), "::", #struct_name), #fallback),
+                    }));
+                    #( #field_refs )*
+                    classes.push(class.as_ref());
                     #merger.fuse_classes(&classes)
                 }
             }
@@ -139,14 +248,18 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
     };
 
     let gen = quote! {
+        #validation
+
         #builder_struct
 
+        #builder_default
+
         #builder_impl
 
         #( #constant_variables )*
 
-        impl IntoBuilder for #struct_ident {
-            type Builder = #builder_ident;
+        impl #impl_generics IntoBuilder for #struct_ident #ty_generics #where_clause {
+            type Builder = #builder_ident #ty_generics;
 
             fn builder() -> Self::Builder {
                 Default::default()
@@ -156,16 +269,16 @@ pub fn theme_class_impl(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl From<#struct_ident> for #builder_ident {
-            fn from(value: #struct_ident) -> Self {
+        impl #impl_generics From<#struct_ident #ty_generics> for #builder_ident #ty_generics #where_clause {
+            fn from(value: #struct_ident #ty_generics) -> Self {
                 #builder_ident {
                     #(#field_idents: Some(value.#field_idents),)*
                 }
             }
         }
 
-        impl From<#builder_ident> for #struct_ident {
-            fn from(value: #builder_ident) -> Self {
+        impl #impl_generics From<#builder_ident #ty_generics> for #struct_ident #ty_generics #build_where {
+            fn from(value: #builder_ident #ty_generics) -> Self {
                 value.build()
             }
         }
@@ -0,0 +1,62 @@
+use proc_macro::TokenStream;
+
+use darling::FromDeriveInput;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::theme::model::TwThemeContainer;
+use crate::variant_macro::to_snake_case;
+
+pub fn theme_impl(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let container = match TwThemeContainer::from_derive_input(&input) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.write_errors());
+        }
+    };
+
+    let enum_ident = &container.ident;
+
+    let variants = container.data.take_enum().unwrap_or_else(Vec::new);
+
+    // Themes are named in snake case, like `#[tw(theme(name = high_contrast))]`.
+    let name_cases = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let name = to_snake_case(&variant_ident.to_string());
+        quote! {
+            #enum_ident::#variant_ident => #name,
+        }
+    });
+
+    let gen = quote! {
+        impl TailwindTheme for #enum_ident {
+            fn theme_name(&self) -> &str {
+                match self {
+                    #( #name_cases )*
+                }
+            }
+        }
+
+        impl AsTailwindTheme<#enum_ident> for #enum_ident {
+            fn as_theme(&self) -> &#enum_ident {
+                self
+            }
+        }
+
+        impl AsTailwindTheme<#enum_ident> for &#enum_ident {
+            fn as_theme(&self) -> &#enum_ident {
+                self
+            }
+        }
+
+        impl Copy for #enum_ident {}
+        impl Clone for #enum_ident {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+    };
+
+    gen.into()
+}
@@ -1,13 +1,18 @@
 use proc_macro::TokenStream;
-use std::collections::HashMap;
 
 use darling::FromDeriveInput;
-use proc_macro2::{Ident, Span};
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{DeriveInput, LitStr};
 
-use crate::theme::construct_theme_container;
-use crate::theme::model::{TwTheme, TwThemeVariantContainer};
+use crate::theme::model::TwThemeVariantContainer;
+use crate::theme::{
+    construct_theme_container, theme_aliases, theme_chain, theme_parents, validate_extends,
+    validate_theme_names,
+};
+use crate::variant_macro::to_snake_case;
 
 pub fn theme_variant_impl(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
@@ -22,6 +27,50 @@ pub fn theme_variant_impl(input: TokenStream) -> TokenStream {
 
     let variants = container.data.take_enum().unwrap_or_else(Vec::new);
 
+    let parents = match theme_parents(&container.theme) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
+    if container.theme_enum.is_none() {
+        let declared = container
+            .theme
+            .iter()
+            .chain(variants.iter().flat_map(|variant| &variant.theme));
+        if let Err(e) = validate_extends(&container.theme, declared) {
+            return TokenStream::from(e.to_compile_error());
+        }
+    }
+
+    // `extends` applies to every variant, so it's only set on the enum.
+    if let Some(extends) = variants
+        .iter()
+        .flat_map(|variant| &variant.theme)
+        .find_map(|theme| theme.extends.as_ref())
+    {
+        return syn::Error::new(extends.span(), "`extends` can only be set on the enum")
+            .to_compile_error()
+            .into();
+    }
+
+    let aliases = match std::iter::once(&container.theme)
+        .chain(variants.iter().map(|variant| &variant.theme))
+        .map(|themes| theme_aliases(themes))
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(v) => v.into_iter().flatten().fold(vec![], |mut aliases, alias| {
+            if !aliases.contains(&alias) {
+                aliases.push(alias);
+            }
+            aliases
+        }),
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
     let base_theme_container = construct_theme_container(container.theme, enum_ident.to_string());
 
     let defaults = variants
@@ -68,26 +117,145 @@ pub fn theme_variant_impl(input: TokenStream) -> TokenStream {
         .map(|variant| {
             let ident = variant.ident;
             let theme = variant.theme;
-            let current_theme_dictionary = construct_theme_dictionary(
+            let mut current_theme_dictionary = construct_theme_container(
                 theme,
-                ident.to_string().to_ascii_uppercase(),
-                enum_ident_string.clone(),
+                format!(
+                    "{}_{}",
+                    ident.to_string().to_ascii_uppercase(),
+                    enum_ident_string
+                ),
             );
+            // Themes that extend another fall back to its classes.
+            for name in parents.keys() {
+                let key = LitStr::new(name, Span::call_site());
+                current_theme_dictionary.entry(key).or_insert_with(|| {
+                    let ident = Ident::new(
+                        &format!(
+                            "{}_{}_{}",
+                            name.to_ascii_uppercase(),
+                            ident.to_string().to_ascii_uppercase(),
+                            enum_ident_string
+                        ),
+                        Span::call_site(),
+                    );
+                    (String::new(), ident)
+                });
+            }
             (ident, current_theme_dictionary)
         })
         .collect::<Vec<_>>();
 
+    let validation = container.theme_enum.as_ref().map(|theme_enum| {
+        let extended = parents
+            .values()
+            .map(|parent| LitStr::new(&parent.to_string(), parent.span()))
+            .collect::<Vec<_>>();
+        let names = base_theme_container
+            .keys()
+            .chain(&extended)
+            .chain(&aliases)
+            .chain(
+                constants
+                    .iter()
+                    .flat_map(|(_, theme_dict)| theme_dict.keys()),
+            );
+        validate_theme_names(theme_enum, names)
+    });
+
+    let merger = {
+        if let Some(merger) = &container.merger {
+            let ident = merger.as_ident();
+            quote! {#ident}
+        } else {
+            quote! {TailwindMerge}
+        }
+    };
+
+    // Extended themes are merged at runtime, behind a function.
+    let lazy = constants
+        .iter()
+        .flat_map(|(_variant, theme_dict)| theme_dict.iter())
+        .filter(|(name, _)| theme_chain(&parents, &name.value()).is_ok_and(|chain| chain.len() > 1))
+        .map(|(_name, (_class, ident))| ident.to_string())
+        .collect::<HashSet<_>>();
+
+    let constant_variables = match constants
+        .iter()
+        .flat_map(|(_variant, theme_dict)| {
+            theme_dict.iter().map(|(name, (_class, ident))| {
+                let chain = theme_chain(&parents, &name.value())?;
+                let classes = chain
+                    .into_iter()
+                    .flat_map(|theme| {
+                        let key = LitStr::new(&theme, Span::call_site());
+                        [
+                            base_theme_container.get(&key).map(|(class, _)| class),
+                            theme_dict.get(&key).map(|(class, _)| class),
+                        ]
+                    })
+                    .flatten()
+                    .filter(|class| !class.is_empty())
+                    .map(String::as_str)
+                    .collect::<Vec<_>>();
+                // The classes of an extended theme override the ones it extends.
+                // They are merged on first use, so the runtime merge config applies.
+                if lazy.contains(&ident.to_string()) {
+                    Ok(quote! {
+                        #[allow(non_snake_case)]
+                        fn #ident() -> &'static str {
+                            use ::std::sync::OnceLock;
+                            static CLASS: OnceLock<String> = OnceLock::new();
+                            CLASS.get_or_init(|| #merger.fuse_classes(&[#( #classes ),*]))
+                        }
+                    })
+                } else {
+                    let class = classes.join(" ");
+                    Ok(quote! {
+                        const #ident: &'static str = #class;
+                    })
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
+    let class_of = |ident: &Ident| -> TokenStream2 {
+        if lazy.contains(&ident.to_string()) {
+            quote! { #ident() }
+        } else {
+            quote! { #ident }
+        }
+    };
+
+    let enum_name = enum_ident.to_string();
     let to_class_cases = constants.iter().map(|(ident, theme_dict)| {
         let to_theme_class_cases = theme_dict.iter().map(|(name, (_class, ident))| {
+            let class = class_of(ident);
             quote! {
-                #name => #ident,
+                #name => #class,
             }
         });
 
+        // Other themes may be registered at runtime, falling back to the base class.
+        let variant_name = to_snake_case(&ident.to_string());
+        let fallback = match theme_dict.get(&LitStr::new("base", Span::call_site())) {
+            Some((_, base)) => class_of(base),
+            None => quote! { "" },
+        };
         let match_theme_class_cases = quote! {
-            match theme.as_ref() {
+            match theme {
                 #( #to_theme_class_cases )*
-                _ => "",
+                _ => registry::variant_class(
+                    theme,
+                    concat!(module_path!(), "::", #enum_name),
+                    #variant_name,
+                    #fallback,
+                ),
             }
         };
 
@@ -96,9 +264,32 @@ pub fn theme_variant_impl(input: TokenStream) -> TokenStream {
         }
     });
 
+    // Themes with `prefix_with` are part of the base theme.
+    let alias_match = (!aliases.is_empty()).then(|| {
+        quote! {
+            let theme = match theme {
+                #( #aliases )|* => "base",
+                theme => theme,
+            };
+        }
+    });
+
+    // With `theme_enum`, only its variants select a theme.
+    let theme_type = match &container.theme_enum {
+        Some(theme_enum) => quote! { #theme_enum },
+        None => quote! { str },
+    };
+
     let into_tailwind = quote! {
         impl AsTailwindThemeClass for #enum_ident {
-            fn as_class(&self, theme: impl AsRef<str>) -> &str {
+            type Theme = #theme_type;
+
+            fn as_class(&self, theme: impl AsTailwindTheme<Self::Theme>) -> &str {
+                self.as_theme_class(theme.as_theme().theme_name())
+            }
+
+            fn as_theme_class(&self, theme: &str) -> &str {
+                #alias_match
                 match self {
                     #( #to_class_cases )*
                 }
@@ -106,21 +297,9 @@ pub fn theme_variant_impl(input: TokenStream) -> TokenStream {
         }
     };
 
-    let constant_variables = constants.iter().flat_map(|(_variant, theme_dict)| {
-        theme_dict.iter().map(|(name, (class, ident))| {
-            if let Some((base_class, _)) = base_theme_container.get(name) {
-                quote! {
-                    const #ident: &'static str = concat!(#base_class, " ", #class);
-                }
-            } else {
-                quote! {
-                    const #ident: &'static str = #class;
-                }
-            }
-        })
-    });
-
     let gen = quote! {
+        #validation
+
         #default_variant
 
         #into_tailwind
@@ -137,38 +316,3 @@ pub fn theme_variant_impl(input: TokenStream) -> TokenStream {
 
     gen.into()
 }
-
-fn construct_theme_dictionary<T>(
-    iterator: T,
-    ident: impl AsRef<str>,
-    enum_ident: impl AsRef<str>,
-) -> HashMap<LitStr, (String, Ident)>
-where
-    T: IntoIterator<Item = TwTheme>,
-{
-    iterator.into_iter().fold(
-        HashMap::<LitStr, (String, Ident)>::new(),
-        |mut container, item| {
-            let value = item.class.as_ref().map(LitStr::value).unwrap_or_default();
-            let key = match item.name {
-                Some(name) => LitStr::new(&name.to_string(), name.span()),
-                None => LitStr::new("base", Span::call_site()),
-            };
-            let ident = Ident::new(
-                &format!(
-                    "{}_{}_{}",
-                    key.value().to_ascii_uppercase(),
-                    ident.as_ref().to_ascii_uppercase(),
-                    enum_ident.as_ref().to_ascii_uppercase()
-                ),
-                Span::call_site(),
-            );
-            let (ref mut class, _) = container.entry(key).or_insert(("".to_owned(), ident));
-            if !class.is_empty() {
-                class.push(' ');
-            }
-            class.push_str(value.as_str());
-            container
-        },
-    )
-}
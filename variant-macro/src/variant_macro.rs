@@ -4,6 +4,9 @@ use quote::quote;
 use syn::DeriveInput;
 
 use crate::model::TwVariantContainer;
+use crate::precompute::merge_classes;
+use crate::strict::Strict;
+use crate::template::ClassTemplate;
 
 pub fn variant_impl(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as DeriveInput);
@@ -15,11 +18,19 @@ pub fn variant_impl(input: TokenStream) -> TokenStream {
     };
 
     let enum_ident = &container.ident;
+    let (impl_generics, ty_generics, where_clause) = container.generics.split_for_impl();
 
     let variants = container.data.take_enum().unwrap_or_else(Vec::new);
 
     let base_class = container.class;
 
+    let strict = match container.strict.map(Strict::new).transpose() {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
     let defaults = variants
         .iter()
         .filter(|v| v.default.is_present())
@@ -46,10 +57,17 @@ pub fn variant_impl(input: TokenStream) -> TokenStream {
         return syn::Error::new(span, error).to_compile_error().into();
     }
 
+    if !defaults[0].fields.is_unit() {
+        let span = defaults[0].default.span();
+        return syn::Error::new(span, "The default variant can't have fields")
+            .to_compile_error()
+            .into();
+    }
+
     let default_variant = defaults.into_iter().next().map(|v| {
         let variant_ident = &v.ident;
         quote! {
-            impl Default for #enum_ident {
+            impl #impl_generics Default for #enum_ident #ty_generics #where_clause {
                 fn default() -> Self {
                     #enum_ident::#variant_ident
                 }
@@ -61,6 +79,7 @@ pub fn variant_impl(input: TokenStream) -> TokenStream {
     let enum_ident_string = enum_ident.to_string().to_ascii_uppercase();
     let constants = variants
         .iter()
+        .filter(|variant| variant.fields.is_unit())
         .map(|variant| {
             (
                 variant,
@@ -84,11 +103,72 @@ pub fn variant_impl(input: TokenStream) -> TokenStream {
         }
     });
 
-    let into_tailwind = quote! {
-        impl AsTailwindClass for #enum_ident {
-            fn as_class(&self) -> &str {
-                match self {
-                    #( #to_class_cases )*
+    let templates = match variants
+        .iter()
+        .filter(|variant| !variant.fields.is_unit())
+        .map(|variant| {
+            Ok((
+                variant,
+                ClassTemplate::parse(&variant.class, &variant.fields)?,
+            ))
+        })
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.to_compile_error());
+        }
+    };
+
+    // Variants with fields format their class, so they can't return a `&str`.
+    let into_tailwind = if templates.is_empty() {
+        quote! {
+            impl AsTailwindClass for #enum_ident {
+                fn as_class(&self) -> &str {
+                    match self {
+                        #( #to_class_cases )*
+                    }
+                }
+            }
+
+            impl Copy for #enum_ident {}
+            impl Clone for #enum_ident {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+        }
+    } else {
+        let escaped_base = base_class.as_ref().map(|base_class| {
+            let base_class = base_class.value().replace('{', "{{").replace('}', "}}");
+            format!("{base_class} ")
+        });
+        let format_cases = templates.iter().map(|(variant, template)| {
+            let variant_ident = &variant.ident;
+            let pattern = template.pattern();
+            let base = escaped_base.as_deref().unwrap_or_default();
+            let format =
+                syn::LitStr::new(&format!("{base}{}", template.format), variant.class.span());
+            quote! {
+                #enum_ident::#variant_ident #pattern => {
+                    std::borrow::Cow::Owned(format!(#format))
+                }
+            }
+        });
+        let borrowed_cases = constants.iter().map(|(variant, constant)| {
+            let variant_ident = &variant.ident;
+            quote! {
+                #enum_ident::#variant_ident => std::borrow::Cow::Borrowed(#constant),
+            }
+        });
+
+        quote! {
+            impl #impl_generics AsTailwindClassCow for #enum_ident #ty_generics #where_clause {
+                fn as_class_cow(&self) -> std::borrow::Cow<'_, str> {
+                    match self {
+                        #( #borrowed_cases )*
+                        #( #format_cases )*
+                    }
                 }
             }
         }
@@ -96,7 +176,14 @@ pub fn variant_impl(input: TokenStream) -> TokenStream {
 
     let constant_variables = constants.iter().map(|(variant, constant)| {
         let class = &variant.class;
-        if let Some(base_class) = &base_class {
+        if container.precompute.is_present() {
+            let base_class = base_class.as_ref().map(syn::LitStr::value);
+            let class = class.value();
+            let merged = merge_classes(&[base_class.as_deref().unwrap_or_default(), &class]);
+            quote! {
+                const #constant: &'static str = #merged;
+            }
+        } else if let Some(base_class) = &base_class {
             quote! {
                 const #constant: &'static str = concat!(#base_class, " ", #class);
             }
@@ -107,20 +194,195 @@ pub fn variant_impl(input: TokenStream) -> TokenStream {
         }
     });
 
+    // Variants are named in snake case, or by `rename`.
+    let names = variants
+        .iter()
+        .map(|variant| match &variant.rename {
+            Some(rename) => rename.value(),
+            None => to_snake_case(&variant.ident.to_string()),
+        })
+        .collect::<Vec<_>>();
+    let variant_idents = variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+
+    let name_impl = quote! {
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            /// The name of the variant, used by `Display` and `FromStr`.
+            pub const fn variant_name(&self) -> &'static str {
+                match self {
+                    #( #enum_ident::#variant_idents { .. } => #names, )*
+                }
+            }
+        }
+
+        impl #impl_generics std::fmt::Display for #enum_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.variant_name())
+            }
+        }
+    };
+
+    // Only unit variants can be listed and parsed.
+    let unit_impl = templates.is_empty().then(|| {
+        let ident_names = variant_idents.iter().map(|ident| ident.to_string());
+        let class_cases = constants.iter().map(|(variant, constant)| {
+            let variant_ident = &variant.ident;
+            quote! { #enum_ident::#variant_ident => #constant, }
+        });
+
+        quote! {
+            impl #impl_generics #enum_ident #ty_generics #where_clause {
+                /// Every variant, in declaration order.
+                pub const VARIANTS: &'static [Self] = &[ #( #enum_ident::#variant_idents ),* ];
+
+                /// The class of the variant, including the base class.
+                pub const fn variant_class(&self) -> &'static str {
+                    match self {
+                        #( #class_cases )*
+                    }
+                }
+            }
+
+            impl #impl_generics std::str::FromStr for #enum_ident #ty_generics #where_clause {
+                type Err = ParseVariantError;
+
+                /// Parses the variant name or identifier, ignoring case.
+                fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                    #(
+                        if s.eq_ignore_ascii_case(#names) || s.eq_ignore_ascii_case(#ident_names) {
+                            return Ok(#enum_ident::#variant_idents);
+                        }
+                    )*
+                    Err(ParseVariantError::new(s, &[ #( #names ),* ]))
+                }
+            }
+        }
+    });
+
+    let serde = templates.is_empty().then(|| serde_impl(enum_ident));
+
+    // Slots with the same name are joined, like `class` on a theme.
+    let slot_cases = variants.iter().flat_map(|variant| {
+        let variant_ident = &variant.ident;
+        let mut slots = Vec::<(String, String)>::new();
+        for slot in &variant.slots {
+            let class = slot
+                .class
+                .as_ref()
+                .map(syn::LitStr::value)
+                .unwrap_or_default();
+            match slots.iter_mut().find(|(name, _)| slot.name == name) {
+                Some((_, classes)) => {
+                    classes.push(' ');
+                    classes.push_str(&class);
+                }
+                None => slots.push((slot.name.to_string(), class)),
+            }
+        }
+        slots.into_iter().map(move |(name, class)| {
+            quote! {
+                (#enum_ident::#variant_ident { .. }, #name) => #class,
+            }
+        })
+    });
+    let slot_names = variants
+        .iter()
+        .flat_map(|variant| &variant.slots)
+        .map(|slot| slot.name.to_string())
+        .fold(vec![], |mut names, name| {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+            names
+        });
+    let slot_impl = quote! {
+        impl #impl_generics AsTailwindSlotClass for #enum_ident #ty_generics #where_clause {
+            const SLOTS: &'static [&'static str] = &[ #( #slot_names ),* ];
+
+            fn as_slot_class(&self, slot: &str) -> &str {
+                match (self, slot) {
+                    #( #slot_cases )*
+                    _ => "",
+                }
+            }
+        }
+    };
+
+    let validation = strict.map(|strict| {
+        let slot_classes = variants
+            .iter()
+            .flat_map(|variant| &variant.slots)
+            .filter_map(|slot| slot.class.as_ref());
+        base_class
+            .iter()
+            .chain(constants.iter().map(|(variant, _)| &variant.class))
+            .chain(slot_classes)
+            .map(|class| strict.validate_class(class))
+            .collect::<proc_macro2::TokenStream>()
+    });
+
     let gen = quote! {
+        #validation
+
         #default_variant
 
         #into_tailwind
 
         #( #constant_variables )*
 
-        impl Copy for #enum_ident {}
-        impl Clone for #enum_ident {
-            fn clone(&self) -> Self {
-                *self
-            }
-        }
+        #name_impl
+
+        #unit_impl
+
+        #slot_impl
+
+        #serde
     };
 
     gen.into()
 }
+
+// Serialized by `Display`, deserialized by `FromStr`.
+#[cfg(feature = "serde")]
+fn serde_impl(enum_ident: &syn::Ident) -> proc_macro2::TokenStream {
+    quote! {
+        impl integrations::serde::Serialize for #enum_ident {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: integrations::serde::Serializer,
+            {
+                serializer.serialize_str(self.variant_name())
+            }
+        }
+
+        impl<'de> integrations::serde::Deserialize<'de> for #enum_ident {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: integrations::serde::Deserializer<'de>,
+            {
+                integrations::serde::deserialize_variant(deserializer)
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn serde_impl(_: &syn::Ident) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+// `IconLg` -> `icon_lg`
+pub fn to_snake_case(ident: &str) -> String {
+    let mut snake = String::with_capacity(ident.len() + 2);
+    let mut previous_lower = false;
+    for c in ident.chars() {
+        if c.is_uppercase() && previous_lower {
+            snake.push('_');
+        }
+        previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}